@@ -1,4 +1,4 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use std::num::ParseIntError;
 
 fn get_word_from_line(line: &str, parse_str_num: bool) -> Result<i32, ParseIntError> {
@@ -51,27 +51,30 @@ fn get_word_from_line(line: &str, parse_str_num: bool) -> Result<i32, ParseIntEr
     combined_string.parse()
 }
 
-struct Day1;
-const DAY: Day1 = Day1;
-impl Day<i32> for Day1 {
-    fn part_1(&self, input: &str) -> i32 {
-        input
-            .lines()
+pub struct Day1;
+pub const DAY: Day1 = Day1;
+impl Day for Day1 {
+    type Parsed = Vec<String>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, AocError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+    fn try_part_1(&self, lines: &Vec<String>) -> Result<i32, AocError> {
+        Ok(lines
+            .iter()
             .map(|line| get_word_from_line(line, false).unwrap())
-            .sum::<i32>()
+            .sum::<i32>())
     }
-    fn part_2(&self, input: &str) -> i32 {
-        input
-            .lines()
+    fn try_part_2(&mut self, lines: &Vec<String>) -> Result<i32, AocError> {
+        Ok(lines
+            .iter()
             .map(|line| get_word_from_line(line, true).unwrap())
-            .sum::<i32>()
+            .sum::<i32>())
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
-}
-
 #[cfg(test)]
 mod tests {
     use crate::get_word_from_line;
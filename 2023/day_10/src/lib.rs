@@ -1,6 +1,6 @@
-use colorize::AnsiColor;
+use colored::Colorize;
 use library::grid::{Direction, UVec2};
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use std::collections::HashSet;
 
 type Coord = UVec2<usize>;
@@ -59,7 +59,8 @@ impl Tile {
     }
 }
 
-struct HotSprings {
+#[derive(Clone)]
+pub struct HotSprings {
     grid: Vec<Vec<char>>,
 }
 impl HotSprings {
@@ -278,18 +279,23 @@ fn shoe_string(hot_springs: HotSprings) -> u64 {
     inside.iter().len() as u64
 }
 
-struct Day10;
-const DAY: Day10 = Day10;
-impl Day<u64> for Day10 {
-    fn part_1(&self, input: &str) -> u64 {
+pub struct Day10;
+pub const DAY: Day10 = Day10;
+impl Day for Day10 {
+    // the pipes and the starting position
+    type Parsed = (HotSprings, Coord);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<(HotSprings, Coord), AocError> {
+        let start = get_start(input).ok_or_else(|| AocError::new("No start"))?;
+        Ok((HotSprings::from_str(input), start))
+    }
+    fn try_part_1(&self, pipes: &(HotSprings, Coord)) -> Result<u64, AocError> {
         let mut part_1_answer: u64 = 0;
 
-        let hot_springs = HotSprings::from_str(input);
-
-        let starting_point = match get_start(input) {
-            Some(a) => a,
-            None => panic!("No start"),
-        };
+        let (hot_springs, starting_point) = pipes;
+        let starting_point = *starting_point;
 
         let mut current_location = starting_point;
         let mut back: Direction = Direction::None;
@@ -313,17 +319,13 @@ impl Day<u64> for Day10 {
             }
         }
 
-        part_1_answer / 2
+        Ok(part_1_answer / 2)
     }
-    fn part_2(&self, input: &str) -> u64 {
+    fn try_part_2(&mut self, pipes: &(HotSprings, Coord)) -> Result<u64, AocError> {
         let mut visited: HashSet<Coord> = HashSet::new();
 
-        let mut hot_springs = HotSprings::from_str(input);
-
-        let starting_point = match get_start(input) {
-            Some(a) => a,
-            None => panic!("No start"),
-        };
+        let (hot_springs, starting_point) = pipes;
+        let (mut hot_springs, starting_point) = (hot_springs.clone(), *starting_point);
 
         let mut current_location = starting_point;
         let mut back: Direction = Direction::None;
@@ -355,48 +357,11 @@ impl Day<u64> for Day10 {
             }
         }
 
-        shoe_string(hot_springs)
+        Ok(shoe_string(hot_springs))
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::Day10;
-    use library::input::Day;
-    const DAY: Day10 = Day10;
-
-    #[test]
-    fn test_example_1() {
-        let input = include_str!("../example.txt");
-        let result = DAY.part_2(input);
-        assert_eq!(result, 1);
-    }
-    #[test]
-    fn test_example_4() {
-        let input = include_str!("../example4.txt");
-        let result = DAY.part_2(input);
-        assert_eq!(result, 4);
-    }
-    #[test]
-    fn test_example_5() {
-        let input = include_str!("../example5.txt");
-        let result = DAY.part_2(input);
-        assert_eq!(result, 8);
-    }
-    #[test]
-    fn test_example_6() {
-        let input = include_str!("../example6.txt");
-        let result = DAY.part_2(input);
-        assert_eq!(result, 4);
-    }
-    #[test]
-    fn test_example_7() {
-        let input = include_str!("../example7.txt");
-        let result = DAY.part_2(input);
-        assert_eq!(result, 10);
-    }
+    library::input::examples!(crate::DAY);
 }
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 use library::grid::Vec2;
-use library::input::{Day, InputType};
+use library::input::{AocError, Day, Params};
 
 #[derive(Copy, Clone)]
 struct Coords(Vec2<usize>);
@@ -13,7 +13,7 @@ impl Coords {
     }
 }
 
-struct Space {
+pub struct Space {
     space: Vec<Vec<char>>,
 }
 impl Space {
@@ -90,53 +90,44 @@ impl Space {
     }
 }
 
-struct Day11;
-const DAY: Day11 = Day11;
-impl Day<usize> for Day11 {
-    fn part_1(&self, input: &str) -> usize {
-        let space = Space::parse(input);
+#[derive(Clone)]
+pub struct Day11 {
+    expansion: usize,
+}
+pub const DAY: Day11 = Day11 { expansion: 1000000 };
+impl Day for Day11 {
+    type Parsed = Space;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Space, AocError> {
+        Ok(Space::parse(input))
+    }
+    fn configure(&mut self, params: &mut Params) -> Result<(), AocError> {
+        if let Some(expansion) = params.take("expansion")? {
+            self.expansion = expansion;
+        }
+        Ok(())
+    }
+    fn try_part_1(&self, space: &Space) -> Result<usize, AocError> {
         let galaxies = space.calculate_expanded_galaxies(2);
 
-        galaxies
+        Ok(galaxies
             .iter()
             .combinations(2)
-            .fold(0, |acc, comb| acc + comb[0].distance(comb[1]))
+            .fold(0, |acc, comb| acc + comb[0].distance(comb[1])))
     }
-    fn part_2(&self, input: &str) -> usize {
-        let space = Space::parse(input);
-        let galaxies: Vec<Coords> = space.calculate_expanded_galaxies(1000000);
+    fn try_part_2(&mut self, space: &Space) -> Result<usize, AocError> {
+        let galaxies: Vec<Coords> = space.calculate_expanded_galaxies(self.expansion);
 
-        galaxies
+        Ok(galaxies
             .iter()
             .combinations(2)
-            .fold(0, |acc, comb| acc + comb[0].distance(comb[1]))
+            .fold(0, |acc, comb| acc + comb[0].distance(comb[1])))
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::Day11;
-    use library::input::Day;
-    const DAY: Day11 = Day11;
-
-    #[test]
-    fn test_part_1() {
-        let input = include_str!("../example.txt");
-
-        let res = DAY.part_1(input);
-
-        assert_eq!(res, 374);
-    }
-    #[test]
-    fn test_part_2() {
-        let input = include_str!("../example.txt");
-
-        let res = DAY.part_2(input);
-
-        assert_eq!(res, 8410);
-    }
+    library::input::examples!(crate::DAY);
 }
@@ -1,9 +1,9 @@
 use core::panic;
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
-struct Record {
+pub struct Record {
     row: Vec<char>,
     damaged: Vec<usize>,
 }
@@ -189,36 +189,40 @@ impl Record {
     }
 }
 
-struct Day12;
-const DAY: Day12 = Day12;
-impl Day<usize> for Day12 {
-    fn part_1(&self, input: &str) -> usize {
-        let records: Vec<Record> = input.lines().filter_map(Record::parse_record).collect();
+pub struct Day12;
+pub const DAY: Day12 = Day12;
+impl Day for Day12 {
+    type Parsed = Vec<Record>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-        records
-            .into_iter()
-            .filter(Record::is_damaged)
+    fn parse(&self, input: &str) -> Result<Vec<Record>, AocError> {
+        Ok(input.lines().filter_map(Record::parse_record).collect())
+    }
+    fn try_part_1(&self, records: &Vec<Record>) -> Result<usize, AocError> {
+        Ok(records
+            .iter()
+            .filter(|record| record.is_damaged())
+            .cloned()
             .map(Record::get_record_variations)
-            .sum()
+            .sum())
     }
-    fn part_2(&self, input: &str) -> usize {
-        input
-            .lines()
-            .filter_map(Record::parse_record)
+    fn try_part_2(&mut self, records: &Vec<Record>) -> Result<usize, AocError> {
+        Ok(records
+            .iter()
+            .cloned()
             .map(Record::unfold_record)
             .map(Record::get_record_variations)
-            .sum()
+            .sum())
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
-}
-
 #[cfg(test)]
 mod tests {
     use crate::Record;
 
+    library::input::examples!(crate::DAY);
+
     #[test]
     fn test_variations() {
         let record = Record {
@@ -249,7 +253,7 @@ mod tests {
 
         for record in records {
             let res = record.valid();
-            assert_eq!(res, true, "record {:?}", record);
+            assert!(res, "record {:?}", record);
 
             for i in 1..record.row.len() {
                 let res = record.partial_compare(i);
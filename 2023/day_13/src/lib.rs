@@ -1,7 +1,7 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 
 #[derive(Debug, Clone, PartialEq)]
-struct Valley {
+pub struct Valley {
     land: Vec<Vec<char>>,
 }
 
@@ -92,13 +92,18 @@ fn find_reflection(valley: &Valley, smudges: usize) -> Option<usize> {
 
     None
 }
-struct Day13;
-const DAY: Day13 = Day13;
-impl Day<usize> for Day13 {
-    fn part_1(&self, input: &str) -> usize {
-        let valleys: Vec<Valley> = parse_input(input);
-
-        valleys
+pub struct Day13;
+pub const DAY: Day13 = Day13;
+impl Day for Day13 {
+    type Parsed = Vec<Valley>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Valley>, AocError> {
+        Ok(parse_input(input))
+    }
+    fn try_part_1(&self, valleys: &Vec<Valley>) -> Result<usize, AocError> {
+        Ok(valleys
             .iter()
             .enumerate()
             .map(|(i, valley)| match find_reflection(valley, 0) {
@@ -108,12 +113,10 @@ impl Day<usize> for Day13 {
                     None => panic!("No perfect mirror {}", i),
                 },
             })
-            .sum()
+            .sum())
     }
-    fn part_2(&self, input: &str) -> usize {
-        let valleys: Vec<Valley> = parse_input(input);
-
-        valleys
+    fn try_part_2(&mut self, valleys: &Vec<Valley>) -> Result<usize, AocError> {
+        Ok(valleys
             .iter()
             .enumerate()
             .map(|(i, valley)| match find_reflection(valley, 1) {
@@ -123,18 +126,16 @@ impl Day<usize> for Day13 {
                     None => panic!("No perfect mirror {}", i),
                 },
             })
-            .sum()
+            .sum())
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
-}
-
 #[cfg(test)]
 mod tests {
     use crate::Valley;
 
+    library::input::examples!(crate::DAY);
+
     #[test]
     fn test_rotate() {
         let valley = Valley {
@@ -1,5 +1,5 @@
-use std::{collections::VecDeque, ops::Rem, time::Instant};
-use library::input::Day;
+use library::input::{AocError, Day};
+use std::{collections::VecDeque, ops::Rem};
 
 fn calculate_weight(lines: &[Vec<char>]) -> usize {
     let res = lines
@@ -86,18 +86,15 @@ fn string_to_char_vec_vec(input: &str) -> Vec<Vec<char>> {
     input.lines().map(|line| line.chars().collect()).collect()
 }
 
-fn part_1(input: &str) -> usize {
-    let formated_input: Vec<Vec<char>> = string_to_char_vec_vec(input);
-    let rolled = roll_rocks(formated_input.clone(), Direction::North);
+fn part_1(formated_input: &[Vec<char>]) -> usize {
+    let rolled = roll_rocks(formated_input.to_vec(), Direction::North);
 
     calculate_weight(&rolled)
 }
 
-fn part_2(input: &str) -> usize {
-    let formated_input: Vec<Vec<char>> = string_to_char_vec_vec(input);
-
+fn part_2(formated_input: &[Vec<char>]) -> usize {
     let mut records: Vec<(usize, Vec<Vec<char>>)> = Vec::new();
-    let mut rolled = formated_input.clone();
+    let mut rolled = formated_input.to_vec();
 
     let mut cleared = false;
 
@@ -137,47 +134,30 @@ fn part_2(input: &str) -> usize {
     calculate_weight(final_rotation)
 }
 
-struct Day14;
-const DAY: Day14 = Day14;
-impl Day<usize> for Day14 {
-    fn part_1(&self, input: &str) -> usize {
-        let space = Space::parse(input);
-        let galaxies = space.calculate_expanded_galaxies(2);
-
-        galaxies
-            .iter()
-            .combinations(2)
-            .fold(0, |acc, comb| acc + comb[0].distance(comb[1]))
+pub struct Day14;
+pub const DAY: Day14 = Day14;
+impl Day for Day14 {
+    type Parsed = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<char>>, AocError> {
+        Ok(string_to_char_vec_vec(input))
     }
-    fn part_2(&self, input: &str) -> usize {
-        let space = Space::parse(input);
-        let galaxies: Vec<Coords> = space.calculate_expanded_galaxies(1000000);
-
-        galaxies
-            .iter()
-            .combinations(2)
-            .fold(0, |acc, comb| acc + comb[0].distance(comb[1]))
+    fn try_part_1(&self, platform: &Vec<Vec<char>>) -> Result<usize, AocError> {
+        Ok(part_1(platform))
+    }
+    fn try_part_2(&mut self, platform: &Vec<Vec<char>>) -> Result<usize, AocError> {
+        Ok(part_2(platform))
     }
-}
-
-fn main() {
-    let input = include_str!("../input.txt");
-
-    let start: Instant = Instant::now();
-    let part_1_answer = part_1(input);
-    let duration = start.elapsed();
-    println!("Part 1 anwer: {}, time: {:?}", part_1_answer, duration);
-
-    let start: Instant = Instant::now();
-    let part_2_answer = part_2(input);
-    let duration = start.elapsed();
-    println!("Part 2 anwer: {}, time: {:?}", part_2_answer, duration);
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{calculate_weight, roll_rocks, string_to_char_vec_vec, Direction};
 
+    library::input::examples!(crate::DAY);
+
     #[test]
     fn test_weight_calc() {
         let input = string_to_char_vec_vec(include_str!("../example_rolled_north.txt"));
@@ -1,5 +1,5 @@
 use library::grid::Vec2;
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use std::fmt;
 
 fn hash_algorithm(chars: impl Iterator<Item = char>) -> usize {
@@ -81,20 +81,23 @@ impl fmt::Display for Lens {
     }
 }
 
-struct Day15;
-const DAY: Day15 = Day15;
-impl Day<usize> for Day15 {
-    fn part_1(&self, input: &str) -> usize {
-        input
-            .split(",")
-            .map(|c| c.chars())
-            .map(hash_algorithm)
-            .sum()
+pub struct Day15;
+pub const DAY: Day15 = Day15;
+impl Day for Day15 {
+    type Parsed = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, AocError> {
+        Ok(input.split(",").map(str::to_string).collect())
+    }
+    fn try_part_1(&self, steps: &Vec<String>) -> Result<usize, AocError> {
+        Ok(steps.iter().map(|c| c.chars()).map(hash_algorithm).sum())
     }
-    fn part_2(&self, input: &str) -> usize {
+    fn try_part_2(&mut self, steps: &Vec<String>) -> Result<usize, AocError> {
         let mut boxes: [Vec<Lens>; 256] = std::array::from_fn(|_| Vec::new());
 
-        for string in input.split(",") {
+        for string in steps {
             let operation = Operation::parse(string);
 
             let box_num = hash_algorithm(operation.label().iter().cloned());
@@ -122,12 +125,13 @@ impl Day<usize> for Day15 {
         }
 
         let boxes: Vec<Vec<Lens>> = boxes.to_vec();
-        Vec2::enumerate(&boxes).iter().fold(0, |acc, (c, lens)| {
+        Ok(Vec2::enumerate(&boxes).iter().fold(0, |acc, (c, lens)| {
             acc + (c.x + 1) * (c.y + 1) * lens.focal_length
-        })
+        }))
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
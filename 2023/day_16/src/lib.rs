@@ -1,7 +1,7 @@
 use library::grid::{Direction, GridState};
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 
-struct Contraption {
+pub struct Contraption {
     layout: Vec<Vec<char>>,
 }
 impl Contraption {
@@ -79,18 +79,22 @@ fn beam_move(contraption: &[Vec<char>], state: GridState) -> Vec<GridState> {
         .collect()
 }
 
-struct Day16;
-const DAY: Day16 = Day16;
-impl Day<usize> for Day16 {
-    fn part_1(&self, input: &str) -> usize {
-        let contraption = Contraption::parse(input);
+pub struct Day16;
+pub const DAY: Day16 = Day16;
+impl Day for Day16 {
+    type Parsed = Contraption;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Contraption, AocError> {
+        Ok(Contraption::parse(input))
+    }
+    fn try_part_1(&self, contraption: &Contraption) -> Result<usize, AocError> {
         let initial = GridState::new(0, 0, Direction::East);
-        contraption.get_energized_count(&initial)
+        Ok(contraption.get_energized_count(&initial))
     }
 
-    fn part_2(&self, input: &str) -> usize {
-        let contraption = Contraption::parse(input);
-
+    fn try_part_2(&mut self, contraption: &Contraption) -> Result<usize, AocError> {
         let width = contraption.layout[0].len() - 1;
         let height = contraption.layout.len() - 1;
 
@@ -116,14 +120,15 @@ impl Day<usize> for Day16 {
                 .flatten()
                 .collect();
 
-        initial_states
+        Ok(initial_states
             .iter()
             .map(|initial| contraption.get_energized_count(initial))
             .max()
-            .unwrap()
+            .unwrap())
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
@@ -1,12 +1,12 @@
 use library::grid::{Direction, GridState, UVec2};
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use std::ops::Range;
 use std::{fmt::Debug, str::FromStr};
 
 mod visited;
 use visited::VisitStates;
 
-struct Facility {
+pub struct Facility {
     grid: Vec<Vec<usize>>,
     dimensions: (usize, usize),
 }
@@ -235,11 +235,17 @@ fn get_lowest_heat_loss(
     visited.get(goal_x, goal_y).get_stopped()
 }
 
-struct Day17;
-const DAY: Day17 = Day17;
-impl Day<usize> for Day17 {
-    fn part_1(&self, input: &str) -> usize {
-        let facility = Facility::parse(input);
+pub struct Day17;
+pub const DAY: Day17 = Day17;
+impl Day for Day17 {
+    type Parsed = Facility;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Facility, AocError> {
+        Ok(Facility::parse(input))
+    }
+    fn try_part_1(&self, facility: &Facility) -> Result<usize, AocError> {
         let (width, height) = facility.dimensions;
         let (width, height) = (width - 1, height - 1);
 
@@ -281,10 +287,14 @@ impl Day<usize> for Day17 {
             max_distance: 3,
         };
 
-        get_lowest_heat_loss(&facility, &initial, (width, height), crucible)
+        Ok(get_lowest_heat_loss(
+            facility,
+            &initial,
+            (width, height),
+            crucible,
+        ))
     }
-    fn part_2(&self, input: &str) -> usize {
-        let facility = Facility::parse(input);
+    fn try_part_2(&mut self, facility: &Facility) -> Result<usize, AocError> {
         // bounds check
         let (width, height) = facility.dimensions;
         let (width, height) = (width - 1, height - 1);
@@ -325,46 +335,22 @@ impl Day<usize> for Day17 {
             next_direction: filter,
             max_distance: 10,
         };
-        get_lowest_heat_loss(&facility, &initial, (width, height), crucible)
+        Ok(get_lowest_heat_loss(
+            facility,
+            &initial,
+            (width, height),
+            crucible,
+        ))
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::{Day17, Facility};
+    use crate::Facility;
     use library::grid::UVec2;
-    use library::input::Day;
-    const DAY: Day17 = Day17;
-    #[test]
-    fn check_example_1() {
-        let input = include_str!("../example.txt");
-        assert_eq!(DAY.part_1(input), 102);
-    }
 
-    #[test]
-    fn check_example_2() {
-        let input = include_str!("../example2.txt");
-        assert_eq!(DAY.part_2(input), 71);
-    }
-    #[test]
-    fn check_example_3() {
-        let input = include_str!("../example3.txt");
-        assert_eq!(DAY.part_2(input), 59);
-    }
-    #[test]
-    fn check_example_4() {
-        let input = include_str!("../example4.txt");
-        assert_eq!(DAY.part_2(input), 44);
-    }
-    #[test]
-    fn check_example_5() {
-        let input = include_str!("../example5.txt");
-        assert_eq!(DAY.part_2(input), 8);
-    }
+    library::input::examples!(crate::DAY);
+
     #[test]
     fn check_weight() {
         let input = include_str!("../example.txt");
@@ -0,0 +1,113 @@
+use library::grid::{Direction, Notation};
+use library::input::{AocError, Day};
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    direction: Direction,
+    length: usize,
+}
+
+fn part_1_decode_instructions(input: &str) -> Result<Vec<Instruction>, AocError> {
+    AocError::parse_lines(input, |line| {
+        let mut split = line.split_whitespace();
+        let direction = split.next().unwrap().parse::<Direction>()?;
+
+        let length = split
+            .next()
+            .unwrap()
+            .parse::<usize>()
+            .map_err(|e| AocError::new(e.to_string()))?;
+        Ok(Instruction { direction, length })
+    })
+}
+
+fn part_2_decode_instructions(input: &str) -> Result<Vec<Instruction>, AocError> {
+    AocError::parse_lines(input, |line| {
+        let split: Vec<&str> = line.split(['(', ')']).collect();
+        let instruction_str = split[1].to_string();
+
+        let length_str = &instruction_str[1..6];
+        let length = usize::from_str_radix(length_str, 16)
+            .map_err(|e| AocError::new(format!("{length_str:?} is not a hex length: {e}")))?;
+        let direction_char = instruction_str.chars().nth(6).unwrap();
+        let direction = Direction::from_char_in(direction_char, Notation::Digit)?;
+
+        Ok(Instruction { length, direction })
+    })
+}
+
+// area of a simple polygon from its vertices in order
+fn shoelace_area(points: &[(i64, i64)]) -> i64 {
+    let twice_area: i64 = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+        .sum();
+
+    twice_area.abs() / 2
+}
+
+fn calculate_area_from_instructions(instructions: Vec<Instruction>) -> u64 {
+    let mut block_coord: Vec<(i64, i64)> = Vec::new();
+
+    // border points
+    let b = instructions
+        .iter()
+        .fold(0, |acc, instruction| acc + instruction.length);
+
+    for instruction in &instructions {
+        let (offset_x, offset_y): (i64, i64) = {
+            match (instruction.direction, instruction.length) {
+                (Direction::East, len) => (len as i64, 0),
+                (Direction::West, len) => (-(len as i64), 0),
+                (Direction::North, len) => (0, len as i64),
+                (Direction::South, len) => (0, -(len as i64)),
+                _ => panic!("Unused Dir"),
+            }
+        };
+
+        let coord = match block_coord.last() {
+            None => (offset_x, offset_y),
+            Some(&(x, y)) => (x + offset_x, y + offset_y),
+        };
+
+        block_coord.push(coord);
+    }
+
+    let area = shoelace_area(&block_coord);
+
+    let inside_area: u64 = (area - (b as i64) / 2 + 1).try_into().unwrap();
+    inside_area + b as u64
+}
+
+pub struct Day18;
+pub const DAY: Day18 = Day18;
+impl Day for Day18 {
+    // the plan read as directions and distances for part 1 and as colour codes for part 2
+    type Parsed = (Vec<Instruction>, Vec<Instruction>);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<(Vec<Instruction>, Vec<Instruction>), AocError> {
+        Ok((
+            part_1_decode_instructions(input)?,
+            part_2_decode_instructions(input)?,
+        ))
+    }
+    fn try_part_1(
+        &self,
+        instructions: &(Vec<Instruction>, Vec<Instruction>),
+    ) -> Result<u64, AocError> {
+        Ok(calculate_area_from_instructions(instructions.0.clone()))
+    }
+    fn try_part_2(
+        &mut self,
+        instructions: &(Vec<Instruction>, Vec<Instruction>),
+    ) -> Result<u64, AocError> {
+        Ok(calculate_area_from_instructions(instructions.1.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
+}
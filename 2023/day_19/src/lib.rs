@@ -1,4 +1,4 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use std::ops::Range;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -24,24 +24,26 @@ enum WorkflowCmp {
 impl WorkflowCmp {
     // pass in a string that has the pattern category then < or > then number
     // ie a<2006
-    fn parse(str: &str) -> Self {
+    fn parse(str: &str) -> Result<Self, AocError> {
         let greater: bool = str.contains("<");
 
         let Some((category_str, threshold_str)) = (match greater {
             true => str.split_once("<"),
             false => str.split_once(">"),
         }) else {
-            panic!("Parsed input cannot be split");
+            return Err(AocError::new(format!("Expected a comparison, got '{str}'")));
         };
 
         let Ok(threshold) = threshold_str.parse() else {
-            panic!("Cannot parse threshold for workflow");
+            return Err(AocError::new(format!(
+                "Cannot parse threshold '{threshold_str}' for workflow"
+            )));
         };
         let Some(category) = category_str.chars().nth(0) else {
-            panic!("Cannot parse category for workflow");
+            return Err(AocError::new("Missing category for workflow"));
         };
 
-        WorkflowCmp::Threshold(threshold, category, greater)
+        Ok(WorkflowCmp::Threshold(threshold, category, greater))
     }
 }
 
@@ -51,56 +53,55 @@ struct WorkflowRule {
     rule: WorkflowCmp,
 }
 #[derive(Debug)]
-struct Workflow {
+pub struct Workflow {
     label: String,
     workflow_rule: Vec<WorkflowRule>,
 }
 
-fn parse_workflows(workflows_str: &str) -> Vec<Workflow> {
-    let mut workflows: Vec<Workflow> = Vec::new();
+fn parse_workflows(workflows_str: &str) -> Result<Vec<Workflow>, AocError> {
+    AocError::parse_lines(workflows_str, parse_workflow)
+}
 
-    for line in workflows_str.lines() {
-        let mut split = line.split(['{', '}', ',']).filter(|a| !a.is_empty());
-        // label is allways the first split item
-        let Some(label) = split.next() else {
-            panic!("Cannot parse label from workflows");
-        };
-        let mut workflow = Workflow {
-            label: label.to_string(),
-            workflow_rule: Vec::new(),
-        };
+fn parse_workflow(line: &str) -> Result<Workflow, AocError> {
+    let mut split = line.split(['{', '}', ',']).filter(|a| !a.is_empty());
+    // label is allways the first split item
+    let Some(label) = split.next() else {
+        return Err(AocError::new("Cannot parse label from workflows"));
+    };
+    let mut workflow = Workflow {
+        label: label.to_string(),
+        workflow_rule: Vec::new(),
+    };
 
-        for rule in split {
-            let rule_split = rule.split(":").collect::<Vec<&str>>();
-
-            match rule_split.len() {
-                // Fallthrough
-                1 => workflow.workflow_rule.push(WorkflowRule {
-                    result: WorkflowResult::parse(rule_split[0]),
-                    rule: WorkflowCmp::Fallthrough,
-                }),
-                // Threshold
-                2 => workflow.workflow_rule.push(WorkflowRule {
-                    result: WorkflowResult::parse(rule_split[1]),
-                    rule: WorkflowCmp::parse(rule_split[0]),
-                }),
-                _ => (),
-            }
+    for rule in split {
+        let rule_split = rule.split(":").collect::<Vec<&str>>();
+
+        match rule_split.len() {
+            // Fallthrough
+            1 => workflow.workflow_rule.push(WorkflowRule {
+                result: WorkflowResult::parse(rule_split[0]),
+                rule: WorkflowCmp::Fallthrough,
+            }),
+            // Threshold
+            2 => workflow.workflow_rule.push(WorkflowRule {
+                result: WorkflowResult::parse(rule_split[1]),
+                rule: WorkflowCmp::parse(rule_split[0])?,
+            }),
+            _ => (),
         }
-        workflows.push(workflow);
     }
-    workflows
+    Ok(workflow)
 }
 
 #[derive(Debug, Clone)]
-struct Ratings {
+pub struct Ratings {
     x: usize,
     m: usize,
     a: usize,
     s: usize,
 }
 impl Ratings {
-    fn parse(str: &str) -> Ratings {
+    fn parse(str: &str) -> Result<Ratings, AocError> {
         let str: Vec<&str> = str
             .split(['{', '}', ','])
             .filter(|&s| s.contains("="))
@@ -114,17 +115,17 @@ impl Ratings {
         };
         for (i, rating) in str.iter().enumerate() {
             let Ok(rating) = rating[2..].parse::<usize>() else {
-                panic!("Cannot parse rating");
+                return Err(AocError::new(format!("Cannot parse rating '{rating}'")));
             };
             match i {
                 0 => ratings.x = rating,
                 1 => ratings.m = rating,
                 2 => ratings.a = rating,
                 3 => ratings.s = rating,
-                a => panic!("parse not expected {a} {rating}"),
+                _ => return Err(AocError::new("Expected 4 ratings")),
             }
         }
-        ratings
+        Ok(ratings)
     }
     fn get_value(&self, c: char) -> usize {
         match c {
@@ -186,8 +187,16 @@ impl RatingsRange {
     }
 }
 
-fn parse_ratings_input(ratings_str: &str) -> Vec<Ratings> {
-    ratings_str.lines().map(Ratings::parse).collect()
+fn parse_ratings_input(ratings_str: &str) -> Result<Vec<Ratings>, AocError> {
+    AocError::parse_lines(ratings_str, Ratings::parse)
+}
+
+// workflows and ratings are separated by a blank line
+fn split_input(input: &str) -> Result<(&str, &str), AocError> {
+    input
+        .split_once("\r\n\r\n")
+        .or_else(|| input.split_once("\n\n"))
+        .ok_or_else(|| AocError::new("Expected workflows and ratings separated by a blank line"))
 }
 
 fn check_machine_part(rating: &Ratings, workflows: &[Workflow]) -> bool {
@@ -306,30 +315,37 @@ fn check_category_range(
     result
 }
 
-struct Day19;
-const DAY: Day19 = Day19;
-impl Day<usize> for Day19 {
-    fn part_1(&self, input: &str) -> usize {
-        let Some((workflows_str, ratings_str)) = input.split_once("\r\n\r\n") else {
-            panic!("Invalid input");
-        };
-        let workflows = parse_workflows(workflows_str);
-        let ratings = parse_ratings_input(ratings_str);
+pub struct Day19;
+pub const DAY: Day19 = Day19;
+impl Day for Day19 {
+    type Parsed = (Vec<Workflow>, Vec<Ratings>);
+    type Part1 = usize;
+    type Part2 = usize;
 
-        ratings
+    fn parse(&self, input: &str) -> Result<(Vec<Workflow>, Vec<Ratings>), AocError> {
+        let (workflows_str, ratings_str) = split_input(input)?;
+        let workflows = parse_workflows(workflows_str)?;
+        let ratings = parse_ratings_input(ratings_str)
+            .map_err(|e| e.after_lines(workflows_str.lines().count() + 1))?;
+
+        Ok((workflows, ratings))
+    }
+    fn try_part_1(
+        &self,
+        (workflows, ratings): &(Vec<Workflow>, Vec<Ratings>),
+    ) -> Result<usize, AocError> {
+        Ok(ratings
             .iter()
-            .map(|rating| match check_machine_part(rating, &workflows) {
+            .map(|rating| match check_machine_part(rating, workflows) {
                 false => 0,
                 true => rating.get_sum(),
             })
-            .sum()
+            .sum())
     }
-    fn part_2(&self, input: &str) -> usize {
-        let Some((workflows_str, _)) = input.split_once("\r\n\r\n") else {
-            panic!("Invalid input");
-        };
-        let workflows = parse_workflows(workflows_str);
-
+    fn try_part_2(
+        &mut self,
+        (workflows, _): &(Vec<Workflow>, Vec<Ratings>),
+    ) -> Result<usize, AocError> {
         let initial_range: Range<usize> = 1..(4000 + 1);
 
         let ratings_range = RatingsRange {
@@ -341,10 +357,11 @@ impl Day<usize> for Day19 {
 
         let initial = WorkflowResult::Workflow("in".to_string());
 
-        check_category_range(ratings_range, &workflows, &initial)
+        Ok(check_category_range(ratings_range, workflows, &initial))
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
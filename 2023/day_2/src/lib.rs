@@ -1,8 +1,8 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use regex::Regex;
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     red: i32,
     blue: i32,
     green: i32,
@@ -64,40 +64,47 @@ const START_GAME: Game = Game {
     blue: 14,
 };
 
-struct Day2;
-const DAY: Day2 = Day2;
-impl Day<i32> for Day2 {
-    fn part_1(&self, input: &str) -> i32 {
+pub struct Day2;
+pub const DAY: Day2 = Day2;
+impl Day for Day2 {
+    // each game's id with the fewest cubes of each colour it needs
+    type Parsed = Vec<(i32, Game)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Vec<(i32, Game)>, AocError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let game_id = get_regex_from_str(line, "Game ([0-9]{1,})");
+                (game_id, get_max_game_colours_from_line(line))
+            })
+            .collect())
+    }
+    fn try_part_1(&self, games: &Vec<(i32, Game)>) -> Result<i32, AocError> {
         let game = START_GAME;
 
         let mut part_1_count: i32 = 0;
 
-        for line in input.lines() {
-            let game_id = get_regex_from_str(line, "Game ([0-9]{1,})");
-            let max_game = get_max_game_colours_from_line(line);
-            let result = check_game(&game, &max_game);
+        for (game_id, max_game) in games {
+            let result = check_game(&game, max_game);
 
             if result {
                 part_1_count += game_id;
             }
         }
-        part_1_count
+        Ok(part_1_count)
     }
-    fn part_2(&self, input: &str) -> i32 {
+    fn try_part_2(&mut self, games: &Vec<(i32, Game)>) -> Result<i32, AocError> {
         let mut part_2_count: i32 = 0;
 
-        for line in input.lines() {
-            let max_game = get_max_game_colours_from_line(line);
+        for (_, max_game) in games {
             part_2_count += max_game.red * max_game.green * max_game.blue;
         }
-        part_2_count
+        Ok(part_2_count)
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
-}
-
 #[cfg(test)]
 mod tests {
     use crate::{check_game, get_max_game_colours_from_line, Game};
@@ -111,7 +118,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, true);
+        assert!(result);
     }
     #[test]
     fn test2() {
@@ -124,7 +131,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, true);
+        assert!(result);
     }
     #[test]
     fn test3() {
@@ -137,7 +144,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test4() {
@@ -150,7 +157,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test5() {
@@ -162,7 +169,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, true);
+        assert!(result);
     }
     #[test]
     fn test_toomanyblue() {
@@ -174,7 +181,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test_toomanyred() {
@@ -186,7 +193,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test_toomanygreen() {
@@ -198,7 +205,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test_id_1() {
@@ -210,7 +217,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test_id_2() {
@@ -222,7 +229,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test_id_3() {
@@ -234,7 +241,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test_id_53() {
@@ -246,7 +253,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, true);
+        assert!(result);
     }
     #[test]
     fn test_exact_green() {
@@ -258,7 +265,7 @@ mod tests {
         };
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
-        assert_eq!(result, true);
+        assert!(result);
     }
     #[test]
     fn test_exact_blue() {
@@ -271,7 +278,7 @@ mod tests {
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
     #[test]
     fn test_exact_red() {
@@ -284,7 +291,7 @@ mod tests {
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
     #[test]
     fn test_1more_green() {
@@ -297,7 +304,7 @@ mod tests {
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test_1more_blue() {
@@ -310,7 +317,7 @@ mod tests {
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test_1more_red() {
@@ -323,7 +330,7 @@ mod tests {
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
     #[test]
     fn test_1less_green() {
@@ -336,7 +343,7 @@ mod tests {
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
     #[test]
     fn test_1less_blue() {
@@ -349,7 +356,7 @@ mod tests {
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
     #[test]
     fn test_1less_red() {
@@ -362,6 +369,6 @@ mod tests {
         let max_game = get_max_game_colours_from_line(&line);
         let result = check_game(&game, &max_game);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 }
@@ -1,6 +1,6 @@
 use crate::ModuleType::{Broadcast, Conjunction, FlipFlop};
 use crate::SignalLevel::{High, Low};
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use library::math::lcm;
 use std::collections::{HashMap, VecDeque};
 use std::ops::Not;
@@ -67,9 +67,11 @@ struct Module {
 }
 
 impl Module {
-    fn from_string(text: &str) -> Self {
+    fn from_string(text: &str) -> Result<Self, AocError> {
         let Some((first, destinations_str)) = text.split_once(" -> ") else {
-            panic!("Cannot split text");
+            return Err(AocError::new(format!(
+                "Expected '<module> -> <destinations>', got '{text}'"
+            )));
         };
 
         let (module_type_str, label) = if first == "broadcaster" {
@@ -82,7 +84,9 @@ impl Module {
             "broadcaster" => Broadcast,
             "%" => FlipFlop(Low),
             "&" => Conjunction(Vec::new()),
-            t => panic!("Unknown Type '{}'", t),
+            t => {
+                return Err(AocError::new(format!("Unknown module type '{t}'")).at_column(1));
+            }
         };
 
         let destinations = destinations_str
@@ -90,11 +94,11 @@ impl Module {
             .map(|dest| dest.to_string())
             .collect();
 
-        Self {
+        Ok(Self {
             label: label.to_string(),
             destinations,
             module_type,
-        }
+        })
     }
 }
 
@@ -104,13 +108,13 @@ enum EndCondition {
 }
 
 #[derive(Clone)]
-struct Machine {
+pub struct Machine {
     modules: HashMap<String, Module>,
 }
 
 impl Machine {
-    fn from_string(input: &str) -> Self {
-        let mut machine: Vec<_> = input.lines().map(Module::from_string).collect();
+    fn from_string(input: &str) -> Result<Self, AocError> {
+        let mut machine: Vec<_> = AocError::parse_lines(input, Module::from_string)?;
 
         let machine_clone = machine.clone();
 
@@ -134,7 +138,7 @@ impl Machine {
             modules.insert(module.label.clone(), module);
         }
 
-        Machine { modules }
+        Ok(Machine { modules })
     }
     fn press_button(&mut self, condition: EndCondition) -> (u64, u64) {
         let mut signals: VecDeque<(String, String, SignalLevel)> = VecDeque::new();
@@ -187,61 +191,75 @@ impl Machine {
     }
 }
 
-fn find_conjecture(machine: &Machine, module_label: &str) -> Option<String> {
+fn find_conjecture(machine: &Machine, module_label: &str) -> Result<Option<String>, AocError> {
     let Some(module) = machine.modules.get(module_label) else {
-        panic!("Cannot find module");
+        return Err(AocError::new(format!(
+            "Cannot find module '{module_label}'"
+        )));
     };
 
     if let Conjunction(_) = module.module_type {
-        return Some(module_label.to_string());
+        return Ok(Some(module_label.to_string()));
     }
 
     for destination in module.destinations.iter() {
-        match find_conjecture(machine, destination) {
-            None => (),
-            Some(label) => return Some(label),
+        if let Some(label) = find_conjecture(machine, destination)? {
+            return Ok(Some(label));
         }
     }
 
-    None
+    Ok(None)
 }
 
-struct Day20;
-const DAY: Day20 = Day20;
-impl Day<u64> for Day20 {
-    fn part_1(&self, input: &str) -> u64 {
-        let mut machine = Machine::from_string(input);
+pub struct Day20;
+pub const DAY: Day20 = Day20;
+impl Day for Day20 {
+    type Parsed = Machine;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Machine, AocError> {
+        Machine::from_string(input)
+    }
+    fn try_part_1(&self, machine: &Machine) -> Result<u64, AocError> {
+        let mut machine = machine.clone();
 
         let (low_pulses, high_pulses) = machine.press_button(EndCondition::ButtonPresses(1000));
 
-        high_pulses * low_pulses
+        Ok(high_pulses * low_pulses)
     }
-    fn part_2(&self, input: &str) -> u64 {
-        let machine = Machine::from_string(input);
-
+    fn try_part_2(&mut self, machine: &Machine) -> Result<u64, AocError> {
         let Some(broadcaster) = machine.modules.get("broadcaster") else {
-            panic!("Can't find broadcaster")
+            return Err(AocError::new("Can't find broadcaster"));
         };
 
-        let mut partial_answers = broadcaster
-            .destinations
-            .iter()
-            .filter_map(|destination| find_conjecture(&machine, destination))
-            .map(|conj_label| {
-                machine
-                    .clone()
-                    .press_button(EndCondition::ModuleReceiveSignal(conj_label, Low))
-                    .0
-            });
-
-        let initial = partial_answers.next().unwrap();
-
-        partial_answers.fold(initial, |part_2_answer, partial_answer| {
-            lcm(part_2_answer, partial_answer)
-        })
+        let mut conjunctions = Vec::new();
+        for destination in &broadcaster.destinations {
+            if let Some(label) = find_conjecture(machine, destination)? {
+                conjunctions.push(label);
+            }
+        }
+
+        let mut partial_answers = conjunctions.into_iter().map(|conj_label| {
+            machine
+                .clone()
+                .press_button(EndCondition::ModuleReceiveSignal(conj_label, Low))
+                .0
+        });
+
+        let Some(initial) = partial_answers.next() else {
+            return Err(AocError::new("Broadcaster does not lead to a conjunction"));
+        };
+
+        Ok(
+            partial_answers.fold(initial, |part_2_answer, partial_answer| {
+                lcm(part_2_answer, partial_answer)
+            }),
+        )
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
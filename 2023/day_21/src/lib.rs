@@ -0,0 +1,326 @@
+use crate::Tile::{GardenPlot, Rocks, Start};
+use colored::Colorize;
+use library::grid::{Grid, SparseGrid, Vec2};
+use library::input::{AocError, Day, InputType, Params};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Tile {
+    Start,
+    GardenPlot,
+    Rocks,
+}
+
+impl Tile {
+    fn from_char(char: char) -> Self {
+        match char {
+            'S' => Start,
+            '.' => GardenPlot,
+            '#' => Rocks,
+            a => panic!("Invalid tile map: {}", a),
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Start => 'S',
+            GardenPlot => '.',
+            Rocks => '#',
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Garden {
+    grid: Grid<Tile>,
+    steps: SparseGrid<u32>,
+}
+
+impl Garden {
+    pub const DEFAULT_STEP: u32 = u32::MAX;
+
+    fn from_string(input: &str) -> Result<Self, AocError> {
+        let grid = Grid::parse(input, Tile::from_char)?;
+
+        Ok(Self {
+            grid,
+            steps: SparseGrid::new(),
+        })
+    }
+    fn find_start(&self) -> Vec2<i64> {
+        let start = self.grid.find(&Start).expect("Cannot find start");
+        Vec2::new(start.x as i64, start.y as i64)
+    }
+    fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    fn visited(&self, elf: &Elf) -> bool {
+        // checks if spot has been visited before with fewer steps
+        let steps = match self.steps.get(elf.coords) {
+            None => Garden::DEFAULT_STEP,
+            Some(step) => *step,
+        };
+
+        steps <= elf.steps
+    }
+
+    fn visit(&mut self, elf: &Elf) {
+        self.steps.insert(elf.coords, elf.steps);
+    }
+
+    fn get_step_coords(&self) -> impl Iterator<Item = &u32> {
+        self.steps.values()
+    }
+
+    fn _print_steps_range(&self, y_max: i64, y_min: i64, x_max: i64, x_min: i64) {
+        let entries: Vec<(Vec2<i64>, &u32)> = self
+            .steps
+            .window(Vec2::new(x_min, y_min), Vec2::new(x_max - 1, y_max - 1))
+            .collect();
+
+        println!("{:?}", entries)
+    }
+
+    // the score of every copy of the map that was reached, keyed by which copy it is
+    fn calculate_score_for_each_universe(&self, max_steps: u32) -> HashMap<Vec2<i64>, u64> {
+        let tiled = self.grid.tiled();
+        let mut scores = HashMap::new();
+
+        for (coords, step) in self.steps.iter() {
+            *scores.entry(tiled.copy_of(coords)).or_default() +=
+                Self::reachable_score(*step, max_steps);
+        }
+
+        scores
+    }
+
+    fn reachable_score(step: u32, max_steps: u32) -> u64 {
+        // even coordinates can only be reached with an even amount of steps an vise versa with odd steps
+        let even_coord = step.is_multiple_of(2);
+        let even_max_step = max_steps.is_multiple_of(2);
+        match even_coord == even_max_step {
+            true => 1,
+            false => 0,
+        }
+    }
+
+    fn calculate_score(&self, max_steps: u32) -> u64 {
+        self.get_step_coords()
+            .map(|step| Self::reachable_score(*step, max_steps))
+            .sum()
+    }
+
+    fn get_color(&self, coords: Vec2<i64>) -> u8 {
+        let copy = self.grid.tiled().copy_of(coords);
+        match (copy.x.rem_euclid(2), copy.y.rem_euclid(2)) {
+            (0, 0) => 0,
+            (_, 0) => 1,
+            (0, _) => 2,
+            (_, _) => 3,
+        }
+    }
+
+    fn find_next(&mut self, elf: Elf) -> VecDeque<Elf> {
+        let coords = elf.coords;
+
+        if elf.steps == elf.max_steps {
+            return VecDeque::new();
+        }
+
+        let tiled = self.grid.tiled();
+
+        let mut coords_list = VecDeque::new();
+
+        for next_coords in tiled.neighbours(coords) {
+            if *tiled.get(next_coords) == Rocks {
+                continue;
+            }
+
+            let new_elf = Elf {
+                coords: next_coords,
+                steps: elf.steps + 1,
+                ..elf
+            };
+
+            if self.visited(&new_elf) {
+                continue;
+            }
+
+            coords_list.push_front(new_elf);
+        }
+        coords_list
+    }
+}
+
+impl fmt::Display for Garden {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min_visited, max_visited) = self.steps.bounds().unwrap();
+
+        let window = self.grid.tiled().render(
+            Vec2::new(min_visited.x - 10, min_visited.y - 10),
+            Vec2::new(max_visited.x + 10, max_visited.y + 10),
+            |coords, tile| {
+                let s = if let Some(steps) = self.steps.get(coords) {
+                    if steps % 2 == 0 {
+                        "E".to_string()
+                    } else {
+                        "O".to_string()
+                    }
+                } else {
+                    format!("{}", tile)
+                };
+
+                match self.get_color(coords) {
+                    0 => s.red(),
+                    1 => s.blue(),
+                    2 => s.magenta(),
+                    3 => s.green(),
+                    _ => panic!(),
+                }
+            },
+        );
+
+        write!(f, "{window}")
+    }
+}
+
+struct Elf {
+    max_steps: u32,
+    steps: u32,
+    coords: Vec2<i64>,
+}
+
+#[derive(Clone)]
+pub struct Day21 {
+    part_1_steps: u32,
+    part_2_steps: u32,
+}
+pub const DAY: Day21 = Day21 {
+    part_1_steps: 64,
+    part_2_steps: 26501365,
+};
+impl Day for Day21 {
+    type Parsed = Garden;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Garden, AocError> {
+        Garden::from_string(input)
+    }
+    fn select_params(&mut self, input_type: &InputType) {
+        *self = if input_type.is_example() {
+            Day21 {
+                part_1_steps: 6,
+                part_2_steps: 5000,
+            }
+        } else {
+            DAY
+        };
+    }
+    fn configure(&mut self, params: &mut Params) -> Result<(), AocError> {
+        if let Some(steps) = params.take("part_1_steps")? {
+            self.part_1_steps = steps;
+        }
+        if let Some(steps) = params.take("part_2_steps")? {
+            self.part_2_steps = steps;
+        }
+        Ok(())
+    }
+    fn try_part_1(&self, garden: &Garden) -> Result<u64, AocError> {
+        let max_steps: u32 = self.part_1_steps;
+        let mut garden = garden.clone();
+
+        let gardener = Elf {
+            max_steps,
+            steps: 0,
+            coords: garden.find_start(),
+        };
+
+        let mut gardeners = VecDeque::from([gardener]);
+
+        while let Some(gardener) = gardeners.pop_front() {
+            if garden.visited(&gardener) {
+                continue;
+            }
+
+            garden.visit(&gardener);
+            let mut next = garden.find_next(gardener);
+            gardeners.append(&mut next);
+        }
+        Ok(garden.calculate_score(max_steps))
+    }
+    fn try_part_2(&mut self, garden: &Garden) -> Result<u64, AocError> {
+        let width = garden.width() as i64;
+        let height = garden.height() as i64;
+        if width != height {
+            panic!("Not sqare");
+        }
+
+        let reduced_max_steps = if self.part_2_steps > width as u32 * 4 {
+            (self.part_2_steps as i64 % width + width * 2) as u32
+        } else {
+            println!("Using part 1 method due to size");
+            self.part_1_steps = self.part_2_steps;
+            return Ok(self.part_1(garden));
+        };
+
+        let mut garden = garden.clone();
+
+        let gardener = Elf {
+            max_steps: reduced_max_steps,
+            steps: 0,
+            coords: garden.find_start(),
+        };
+
+        let mut gardeners = VecDeque::from([gardener]);
+
+        while let Some(gardener) = gardeners.pop_front() {
+            if garden.visited(&gardener) {
+                continue;
+            }
+
+            garden.visit(&gardener);
+            let mut next = garden.find_next(gardener);
+            gardeners.append(&mut next);
+        }
+
+        let scores = garden.calculate_score_for_each_universe(self.part_2_steps);
+
+        let score = |x: i64, y: i64| scores.get(&Vec2::new(x, y)).copied().unwrap_or(0);
+
+        let e1 = score(0, 0);
+        let e2 = score(-1, 0);
+
+        let a = score(-1, -1) + score(-1, 1) + score(1, 1) + score(1, -1);
+
+        let t = score(-2, 0) + score(0, -2) + score(2, 0) + score(0, 2);
+
+        // only the copies three to the left and three above are counted
+        let d = score(-3, 0) + score(0, -3);
+
+        let b = score(-1, -2) + score(-2, 1) + score(1, -2) + score(1, 2);
+
+        let s = (width - 1) / 2;
+        let n = ((self.part_2_steps as i64 - s) / width) as u64;
+
+        Ok((n - 1).pow(2) * e1 + n.pow(2) * e2 + (n - 1) * a + n * b + t + d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
+}
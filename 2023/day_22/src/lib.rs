@@ -1,6 +1,6 @@
 use core::fmt;
-use library::input::{Day, InputType};
-use std::{collections::HashMap, fmt::Formatter, hash::Hash};
+use library::input::{AocError, Day};
+use std::{cmp::Reverse, collections::HashMap, fmt::Formatter, hash::Hash};
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 struct Coords3D {
@@ -165,7 +165,7 @@ impl Block {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct SandStack {
+pub struct SandStack {
     coords: HashMap<Coords3D, usize>,
     limit: Coords3D,
 }
@@ -355,13 +355,18 @@ fn get_num_supported(supported: &[(usize, Vec<usize>)]) -> usize {
 }
 
 #[derive(Clone)]
-struct Day18;
-const DAY: Day18 = Day18;
-impl Day<usize> for Day18 {
-    fn part_1(&self, input: &str) -> usize {
-        let sand_stack = SandStack::from_str(input);
-
-        let settled_sand_blocks = sand_stack.settle_blocks(&Axis3D::Z);
+pub struct Day18;
+pub const DAY: Day18 = Day18;
+impl Day for Day18 {
+    type Parsed = SandStack;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<SandStack, AocError> {
+        Ok(SandStack::from_str(input))
+    }
+    fn try_part_1(&self, sand_stack: &SandStack) -> Result<usize, AocError> {
+        let settled_sand_blocks = sand_stack.clone().settle_blocks(&Axis3D::Z);
 
         let supported_each_level: Vec<Vec<(usize, Vec<usize>)>> = (0..settled_sand_blocks.limit.z)
             .rev()
@@ -380,7 +385,7 @@ impl Day<usize> for Day18 {
         for mut remove_list in remove_lists {
             let num_supported = get_num_supported(&remove_list);
             // sort so item that supports the least amount gets removed first
-            remove_list.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+            remove_list.sort_by_key(|a| Reverse(a.1.len()));
 
             for (i, _remove) in remove_list.iter().enumerate() {
                 let mut test_remove_list = remove_list.clone();
@@ -390,11 +395,10 @@ impl Day<usize> for Day18 {
                 }
             }
         }
-        blocks_removed
+        Ok(blocks_removed)
     }
-    fn part_2(&mut self, input: &str) -> usize {
-        let sand_stack = SandStack::from_str(input);
-        let settled_sand_blocks = sand_stack.settle_blocks(&Axis3D::Z);
+    fn try_part_2(&mut self, sand_stack: &SandStack) -> Result<usize, AocError> {
+        let settled_sand_blocks = sand_stack.clone().settle_blocks(&Axis3D::Z);
 
         // ignore first layer because it isn't supported by any blocks
         let blocks_bellow: Vec<Vec<(usize, Vec<usize>)>> = (1..=settled_sand_blocks.limit.z)
@@ -443,35 +447,11 @@ impl Day<usize> for Day18 {
             collapse_by_map.insert(*block_ident, collapse_by_list);
         }
 
-        collapse_by_map.values().fold(0, |a, b| a + b.len())
+        Ok(collapse_by_map.values().fold(0, |a, b| a + b.len()))
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.clone().run(InputType::UserInput)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::Day18;
-    use library::input::Day;
-    const DAY: Day18 = Day18;
-    #[test]
-    fn test1() {
-        let input = include_str!("../example.txt");
-        let result = DAY.part_2(input);
-        assert_eq!(result, 7);
-    }
-    #[test]
-    fn test2() {
-        let input = include_str!("../example2.txt");
-        let result = DAY.part_2(input);
-        assert_eq!(result, 1);
-    }
-    #[test]
-    fn test3() {
-        let input = include_str!("../example3.txt");
-        let result = DAY.part_2(input);
-        assert_eq!(result, 5);
-    }
+    library::input::examples!(crate::DAY);
 }
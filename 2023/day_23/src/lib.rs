@@ -1,7 +1,7 @@
 use colored::Colorize;
 use core::{fmt, str};
-use library::grid::{Coord, Direction};
-use library::input::{Day, InputType};
+use library::grid::{Coord, Direction, Notation};
+use library::input::{AocError, Day};
 use std::{collections::HashMap, fmt::Formatter};
 
 #[derive(PartialEq)]
@@ -13,10 +13,9 @@ enum Tile {
 impl Tile {
     fn from_char(c: char) -> Self {
         match c {
-            '>' => Tile::Slope(Direction::East),
-            '<' => Tile::Slope(Direction::West),
-            '^' => Tile::Slope(Direction::North),
-            'v' => Tile::Slope(Direction::South),
+            '>' | '<' | '^' | 'v' => {
+                Tile::Slope(Direction::from_char_in(c, Notation::Arrow).unwrap())
+            }
             '.' => Tile::Path,
             '#' => Tile::Forest,
             c => panic!("Invalid Tile: {c}"),
@@ -24,11 +23,8 @@ impl Tile {
     }
     fn to_char(&self) -> char {
         match self {
-            Self::Slope(Direction::East) => '>',
-            Self::Slope(Direction::West) => '<',
-            Self::Slope(Direction::North) => '^',
-            Self::Slope(Direction::South) => 'v',
             Self::Slope(Direction::None) => panic!(),
+            Self::Slope(d) => d.to_char(),
             Self::Path => '.',
             Self::Forest => '#',
         }
@@ -48,7 +44,7 @@ impl fmt::Display for Tile {
     }
 }
 
-struct Forest {
+pub struct Forest {
     grid: Vec<Vec<Tile>>,
 }
 impl Forest {
@@ -230,11 +226,17 @@ impl Hiker {
 }
 
 #[derive(Clone)]
-struct Day23;
-const DAY: Day23 = Day23;
-impl Day<usize> for Day23 {
-    fn part_1(&self, input: &str) -> usize {
-        let forest = Forest::from_str(input);
+pub struct Day23;
+pub const DAY: Day23 = Day23;
+impl Day for Day23 {
+    type Parsed = Forest;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Forest, AocError> {
+        Ok(Forest::from_str(input))
+    }
+    fn try_part_1(&self, forest: &Forest) -> Result<usize, AocError> {
         let height = forest.grid.len();
         let start = forest.get_start();
 
@@ -250,15 +252,14 @@ impl Day<usize> for Day23 {
                 longest_hike_len = hiker.score;
                 longest_hike = Some(hiker.clone());
             }
-            let mut next = hiker.hike(&forest, true);
+            let mut next = hiker.hike(forest, true);
             hikers.append(&mut next);
         }
 
-        longest_hike.unwrap().score
+        Ok(longest_hike.unwrap().score)
     }
-    fn part_2(&mut self, input: &str) -> usize {
-        let forest = Forest::from_str(input);
-        let node_map = NodeMap::from_forest(&forest);
+    fn try_part_2(&mut self, forest: &Forest) -> Result<usize, AocError> {
+        let node_map = NodeMap::from_forest(forest);
 
         let height = forest.grid.len();
         let start = forest.get_start();
@@ -279,10 +280,11 @@ impl Day<usize> for Day23 {
             hikers.append(&mut next);
         }
 
-        longest_hike.unwrap().score
+        Ok(longest_hike.unwrap().score)
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.clone().run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
@@ -1,5 +1,5 @@
 use core::fmt;
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use rand::prelude::*;
 use std::collections::HashMap;
 
@@ -45,7 +45,7 @@ impl fmt::Display for Component {
 }
 
 #[derive(Debug, Clone)]
-struct Apparatus {
+pub struct Apparatus {
     components: HashMap<Component, Vec<Link>>,
 }
 impl Apparatus {
@@ -185,13 +185,17 @@ impl fmt::Display for Apparatus {
 }
 
 #[derive(Clone)]
-struct Day25;
-const DAY: Day25 = Day25;
-impl Day<usize> for Day25 {
-    fn part_1(&self, input: &str) -> usize {
-        // process input
-        let orig_apparatus = Apparatus::from_str(input);
+pub struct Day25;
+pub const DAY: Day25 = Day25;
+impl Day for Day25 {
+    type Parsed = Apparatus;
+    type Part1 = usize;
+    type Part2 = usize;
 
+    fn parse(&self, input: &str) -> Result<Apparatus, AocError> {
+        Ok(Apparatus::from_str(input))
+    }
+    fn try_part_1(&self, orig_apparatus: &Apparatus) -> Result<usize, AocError> {
         let mut rng = rand::rng();
 
         for _i in 0..100 {
@@ -249,16 +253,17 @@ impl Day<usize> for Day25 {
             let side_2_count = test_apparatus.connected_components(&test_nodes[1]).len();
 
             if side_1_count != side_2_count {
-                return side_1_count * side_2_count;
+                return Ok(side_1_count * side_2_count);
             }
         }
         panic!("Did not find answer")
     }
-    fn part_2(&mut self, _input: &str) -> usize {
-        0
+    fn try_part_2(&mut self, _apparatus: &Apparatus) -> Result<usize, AocError> {
+        Ok(0)
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.clone().run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
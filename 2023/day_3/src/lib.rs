@@ -1,4 +1,5 @@
-use library::input::{Day, InputType};
+use library::grid::Direction8;
+use library::input::{AocError, Day};
 
 fn has_special_char(compare_top: &str, start_cmp_val: usize, end_value: usize) -> bool {
     let compare_top_section = compare_top.get(start_cmp_val..end_value);
@@ -216,17 +217,14 @@ fn part_2(input: &str) -> i32 {
     let width = lines[0].len();
     let height = lines.len();
 
-    // offsets from -1..1, -1..1 excluding 0,0
-    let offset_to_check: Vec<[i16; 2]> = vec![
-        [-1, 1],
-        [0, 1],
-        [1, 1],
-        [-1, 0],
-        [1, 0],
-        [-1, -1],
-        [0, -1],
-        [1, -1],
-    ];
+    // every neighbour, diagonals included
+    let offset_to_check: Vec<[i16; 2]> = Direction8::ALL
+        .iter()
+        .map(|direction| {
+            let (x, y) = direction.get_translation();
+            [x, y]
+        })
+        .collect();
 
     let mut askii_coords: Vec<Coord> = Vec::new();
     let mut checked_coords: Vec<Coord> = Vec::new();
@@ -255,24 +253,31 @@ fn part_2(input: &str) -> i32 {
     day_2_result
 }
 
-struct Day3;
-const DAY: Day3 = Day3;
-impl Day<i32> for Day3 {
-    fn part_1(&self, input: &str) -> i32 {
-        part_1(input)
+pub struct Day3;
+pub const DAY: Day3 = Day3;
+impl Day for Day3 {
+    // the schematic is searched as text
+    type Parsed = String;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<String, AocError> {
+        Ok(input.to_string())
     }
-    fn part_2(&self, input: &str) -> i32 {
-        part_2(input)
+    fn try_part_1(&self, input: &String) -> Result<i32, AocError> {
+        Ok(part_1(input))
+    }
+    fn try_part_2(&mut self, input: &String) -> Result<i32, AocError> {
+        Ok(part_2(input))
     }
-}
-
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::get_number_from_lines;
+
+    library::input::examples!(crate::DAY);
+
     #[test]
     fn test1() {
         let top_line =    ".............*..........948..808..158..........%...............*................&......*537.......................=............-....529.....";
@@ -1,4 +1,4 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use std::collections::HashSet;
 
 fn turn_string_into_number_vect(input: &str) -> HashSet<i32> {
@@ -22,7 +22,7 @@ fn get_part_1_score(num_match: u32) -> usize {
     }
 }
 
-struct ScratchCard {
+pub struct ScratchCard {
     your_numbers: HashSet<i32>,
     winning_numbers: HashSet<i32>,
 }
@@ -60,15 +60,20 @@ impl ScratchCard {
     }
 }
 
-struct Day4;
-const DAY: Day4 = Day4;
-impl Day<usize> for Day4 {
-    fn part_1(&self, str: &str) -> usize {
-        let mut part_1_answer: usize = 0;
+pub struct Day4;
+pub const DAY: Day4 = Day4;
+impl Day for Day4 {
+    type Parsed = Vec<ScratchCard>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-        for line in str.lines() {
-            let scratch_card = ScratchCard::parse(line);
+    fn parse(&self, str: &str) -> Result<Vec<ScratchCard>, AocError> {
+        Ok(str.lines().map(ScratchCard::parse).collect())
+    }
+    fn try_part_1(&self, scratch_cards: &Vec<ScratchCard>) -> Result<usize, AocError> {
+        let mut part_1_answer: usize = 0;
 
+        for scratch_card in scratch_cards {
             let matching_numbers = scratch_card.matches();
 
             if matching_numbers > 0 {
@@ -76,14 +81,12 @@ impl Day<usize> for Day4 {
             }
         }
 
-        part_1_answer
+        Ok(part_1_answer)
     }
-    fn part_2(&self, str: &str) -> usize {
-        let mut card_count: Vec<usize> = vec![1; str.lines().count()];
-
-        for (i, line) in str.lines().enumerate() {
-            let scratch_card = ScratchCard::parse(line);
+    fn try_part_2(&mut self, scratch_cards: &Vec<ScratchCard>) -> Result<usize, AocError> {
+        let mut card_count: Vec<usize> = vec![1; scratch_cards.len()];
 
+        for (i, scratch_card) in scratch_cards.iter().enumerate() {
             let matching_numbers = scratch_card.matches();
 
             let card_dup = match card_count.get(i) {
@@ -100,10 +103,11 @@ impl Day<usize> for Day4 {
             }
         }
 
-        card_count.iter().sum()
+        Ok(card_count.iter().sum())
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
@@ -1,4 +1,4 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use std::fmt;
 use std::fmt::Formatter;
 use std::ops::Range;
@@ -99,7 +99,7 @@ impl fmt::Display for Map {
 }
 
 #[derive(Clone)]
-struct Almanac {
+pub struct Almanac {
     maps: Vec<Map>,
     seeds: Vec<i64>,
 }
@@ -177,25 +177,28 @@ impl ReverseAlmanac {
     }
 }
 
-struct Day5;
-const DAY: Day5 = Day5;
-impl Day<i64> for Day5 {
-    fn part_1(&self, input: &str) -> i64 {
-        let almanac = Almanac::parse(input);
+pub struct Day5;
+pub const DAY: Day5 = Day5;
+impl Day for Day5 {
+    type Parsed = Almanac;
+    type Part1 = i64;
+    type Part2 = i64;
 
-        almanac
+    fn parse(&self, input: &str) -> Result<Almanac, AocError> {
+        Ok(Almanac::parse(input))
+    }
+    fn try_part_1(&self, almanac: &Almanac) -> Result<i64, AocError> {
+        Ok(almanac
             .seeds
             .iter()
             .map(|seed| almanac.get_seed_location(*seed))
             .min()
-            .unwrap()
+            .unwrap())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
+    fn try_part_2(&mut self, almanac: &Almanac) -> Result<i64, AocError> {
         let mut part_2_answer = i64::MAX;
 
-        let almanac = Almanac::parse(input);
-
         let day_2_seed_chunk = almanac.seeds.chunks(2);
         let day_2_seed_range: Vec<Range<i64>> = day_2_seed_chunk
             .map(|seed_chunk| seed_chunk[0]..(seed_chunk[0] + seed_chunk[1]))
@@ -242,17 +245,16 @@ impl Day<i64> for Day5 {
             part_2_answer = final_location;
         }
 
-        part_2_answer
+        Ok(part_2_answer)
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    library::input::examples!(crate::DAY);
+
     #[test]
     fn check_map() {
         let seed_to_soil = Map {
@@ -272,7 +274,7 @@ mod tests {
         assert_eq!(result, 81);
 
         let result = seed_to_soil.lookup(14).is_none();
-        assert_eq!(result, true);
+        assert!(result);
 
         let Some(result) = seed_to_soil.lookup(55) else {
             panic!()
@@ -280,7 +282,7 @@ mod tests {
         assert_eq!(result, 57);
 
         let result = seed_to_soil.lookup(13).is_none();
-        assert_eq!(result, true);
+        assert!(result);
     }
     #[test]
     fn check_almanac() {
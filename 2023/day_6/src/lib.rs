@@ -1,9 +1,9 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use roots::find_roots_quadratic;
 use roots::Roots;
 
 #[derive(Debug)]
-struct Race {
+pub struct Race {
     time: i64,
     distance: i64,
 }
@@ -26,7 +26,7 @@ fn calculate_time_margin(race: &Race) -> i64 {
 }
 
 #[derive(Debug)]
-enum Races {
+pub enum Races {
     Part1(Vec<Race>),
     Part2(Race),
 }
@@ -82,39 +82,46 @@ impl Races {
     }
 }
 
-struct Day6;
-const DAY: Day6 = Day6;
-impl Day<i64> for Day6 {
-    fn part_1(&self, input: &str) -> i64 {
-        let Races::Part1(races) = Races::parse_part_1(input) else {
+pub struct Day6;
+pub const DAY: Day6 = Day6;
+impl Day for Day6 {
+    // the races read as separate numbers for part 1 and as one race for part 2
+    type Parsed = (Races, Races);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<(Races, Races), AocError> {
+        Ok((Races::parse_part_1(input), Races::parse_part_2(input)))
+    }
+    fn try_part_1(&self, races: &(Races, Races)) -> Result<i64, AocError> {
+        let (Races::Part1(races), _) = races else {
             panic!()
         };
 
         let mut part_1_answer: i64 = 1;
 
-        for race in &races {
+        for race in races {
             let margin: i64 = calculate_time_margin(race);
             part_1_answer *= margin;
         }
 
-        part_1_answer
+        Ok(part_1_answer)
     }
-    fn part_2(&self, input: &str) -> i64 {
-        let Races::Part2(race) = Races::parse_part_2(input) else {
+    fn try_part_2(&mut self, races: &(Races, Races)) -> Result<i64, AocError> {
+        let (_, Races::Part2(race)) = races else {
             panic!()
         };
 
-        calculate_time_margin(&race)
+        Ok(calculate_time_margin(race))
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    library::input::examples!(crate::DAY);
+
     #[test]
     fn check_margin_1() {
         let race = Race {
@@ -1,4 +1,5 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
+use std::cmp::Reverse;
 use std::collections::HashMap;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -18,8 +19,8 @@ struct CardStat {
     face: char,
 }
 
-#[derive(Debug)]
-struct Hand {
+#[derive(Debug, Clone)]
+pub struct Hand {
     bid: u64,
     hand_array: [char; 5],    // card array ex. KK677
     card_stat: [CardStat; 5], // card count descending ex. 22100
@@ -80,8 +81,8 @@ impl Hand {
                 }
             }
         }
-        cards_count.sort_by(|a, b| b.cmp(a));
-        hand.card_stat.sort_by(|a, b| b.count.cmp(&a.count));
+        cards_count.sort_by_key(|count| Reverse(*count));
+        hand.card_stat.sort_by_key(|stat| Reverse(stat.count));
 
         // hand kind
         hand.kind = match cards_count {
@@ -174,18 +175,25 @@ fn calculate_answer(hands: &mut [Hand], card_order: &HashMap<char, i32>) -> u64
     score
 }
 
-struct Day7;
-const DAY: Day7 = Day7;
-impl Day<u64> for Day7 {
-    fn part_1(&self, input: &str) -> u64 {
+pub struct Day7;
+pub const DAY: Day7 = Day7;
+impl Day for Day7 {
+    type Parsed = Vec<Hand>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Hand>, AocError> {
+        Ok(input.lines().map(Hand::parse).collect())
+    }
+    fn try_part_1(&self, hands: &Vec<Hand>) -> Result<u64, AocError> {
         let part_1_card_cmp = Hand::card_strength();
 
-        let mut hands: Vec<Hand> = input.lines().map(Hand::parse).collect();
+        let mut hands = hands.clone();
 
-        calculate_answer(&mut hands, &part_1_card_cmp)
+        Ok(calculate_answer(&mut hands, &part_1_card_cmp))
     }
-    fn part_2(&self, input: &str) -> u64 {
-        let mut hands: Vec<Hand> = input.lines().map(Hand::parse).collect();
+    fn try_part_2(&mut self, hands: &Vec<Hand>) -> Result<u64, AocError> {
+        let mut hands = hands.clone();
 
         let mut part_2_card_cmp = Hand::card_strength();
         part_2_card_cmp.insert('J', 1);
@@ -197,10 +205,11 @@ impl Day<u64> for Day7 {
                 hand.kind = new_kind;
             }
         }
-        calculate_answer(&mut hands, &part_2_card_cmp)
+        Ok(calculate_answer(&mut hands, &part_2_card_cmp))
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
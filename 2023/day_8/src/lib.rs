@@ -1,8 +1,8 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use library::math::lcm;
 use std::collections::HashMap;
 
-struct System {
+pub struct System {
     instructions: Vec<char>,
     network: HashMap<[char; 3], Node>,
 }
@@ -150,17 +150,24 @@ fn _brute_force_part_2(
     day_2_answer
 }
 
-struct Day8;
-const DAY: Day8 = Day8;
-impl Day<u64> for Day8 {
-    fn part_1(&self, input: &str) -> u64 {
-        let system = System::parse(input);
+pub struct Day8;
+pub const DAY: Day8 = Day8;
+impl Day for Day8 {
+    type Parsed = System;
+    type Part1 = u64;
+    type Part2 = u64;
 
-        get_distance_to_z(&system.network, &system.instructions, ['A'; 3])
+    fn parse(&self, input: &str) -> Result<System, AocError> {
+        Ok(System::parse(input))
     }
-    fn part_2(&self, input: &str) -> u64 {
-        let system = System::parse(input);
-
+    fn try_part_1(&self, system: &System) -> Result<u64, AocError> {
+        Ok(get_distance_to_z(
+            &system.network,
+            &system.instructions,
+            ['A'; 3],
+        ))
+    }
+    fn try_part_2(&mut self, system: &System) -> Result<u64, AocError> {
         let day_2_starting: Vec<[char; 3]> = system
             .network
             .keys()
@@ -176,10 +183,11 @@ impl Day<u64> for Day8 {
         // add number of instructions
         ring_lengths.push(system.instructions.len() as u64);
 
-        lcm_of_vec(&ring_lengths)
+        Ok(lcm_of_vec(&ring_lengths))
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
@@ -1,6 +1,7 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 
-struct Oasis {
+#[derive(Clone)]
+pub struct Oasis {
     reports: Vec<Prediction>,
 }
 
@@ -12,7 +13,7 @@ impl Oasis {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Prediction {
     prediction: Vec<Vec<i64>>,
 }
@@ -63,13 +64,20 @@ impl Prediction {
     }
 }
 
-struct Day9;
-const DAY: Day9 = Day9;
-impl Day<i64> for Day9 {
-    fn part_1(&self, input: &str) -> i64 {
-        let mut oasis = Oasis::parse(input);
+pub struct Day9;
+pub const DAY: Day9 = Day9;
+impl Day for Day9 {
+    type Parsed = Oasis;
+    type Part1 = i64;
+    type Part2 = i64;
 
-        oasis
+    fn parse(&self, input: &str) -> Result<Oasis, AocError> {
+        Ok(Oasis::parse(input))
+    }
+    fn try_part_1(&self, oasis: &Oasis) -> Result<i64, AocError> {
+        let mut oasis = oasis.clone();
+
+        Ok(oasis
             .reports
             .iter_mut()
             .map(|report| {
@@ -87,14 +95,13 @@ impl Day<i64> for Day9 {
                     .unwrap()
                     .to_owned()
             })
-            .sum::<i64>()
+            .sum::<i64>())
     }
-    fn part_2(&self, input: &str) -> i64 {
-        let oasis = Oasis::parse(input);
-
-        oasis
+    fn try_part_2(&mut self, oasis: &Oasis) -> Result<i64, AocError> {
+        Ok(oasis
             .reports
-            .into_iter()
+            .iter()
+            .cloned()
             .map(|mut report| {
                 while !report.last_prediction() {
                     report.generate_difference();
@@ -110,10 +117,11 @@ impl Day<i64> for Day9 {
                     .unwrap()
                     .to_owned()
             })
-            .sum::<i64>()
+            .sum::<i64>())
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
@@ -1,7 +1,8 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use std::iter;
 
-struct List {
+#[derive(Clone)]
+pub struct List {
     left_list: Vec<u32>,
     right_list: Vec<u32>,
 }
@@ -20,24 +21,30 @@ impl List {
     }
 }
 
-struct Day1;
-const DAY: Day1 = Day1;
-impl Day<u32> for Day1 {
-    fn part_1(&self, input: &str) -> u32 {
-        let mut list = List::parse(input);
+pub struct Day1;
+pub const DAY: Day1 = Day1;
+impl Day for Day1 {
+    type Parsed = List;
+    type Part1 = u32;
+    type Part2 = u32;
 
+    fn parse(&self, input: &str) -> Result<List, AocError> {
+        Ok(List::parse(input))
+    }
+    fn try_part_1(&self, list: &List) -> Result<u32, AocError> {
+        let mut list = list.clone();
         list.left_list.sort();
         list.right_list.sort();
 
-        iter::zip(list.left_list, list.right_list).fold(0, |acc, (l, r)| acc + l.abs_diff(r))
+        Ok(iter::zip(list.left_list, list.right_list).fold(0, |acc, (l, r)| acc + l.abs_diff(r)))
     }
-    fn part_2(&self, input: &str) -> u32 {
-        let mut list = List::parse(input);
-
+    fn try_part_2(&mut self, list: &List) -> Result<u32, AocError> {
+        let mut list = list.clone();
         list.left_list.sort();
         list.right_list.sort();
 
-        list.left_list
+        Ok(list
+            .left_list
             .into_iter()
             .map(|left| {
                 list.right_list
@@ -46,10 +53,11 @@ impl Day<u32> for Day1 {
                     .count() as u32
                     * left
             })
-            .sum()
+            .sum())
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
@@ -0,0 +1,97 @@
+use library::grid::{Coord, Grid};
+use library::input::{AocError, Day};
+
+pub struct TopologicalMap {
+    map: Grid<u32>,
+}
+impl TopologicalMap {
+    fn from_input(input: &str) -> Result<Self, AocError> {
+        let map = Grid::parse(input, |c| c.to_digit(10).unwrap_or(99))?;
+
+        Ok(Self { map })
+    }
+
+    fn get_start(&self) -> Vec<Coord> {
+        self.map.find_all(&0)
+    }
+
+    fn next(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        let curr_height = self.map[coord];
+
+        self.map
+            .neighbours(coord)
+            .filter(move |&next| self.map[next] == curr_height + 1)
+    }
+
+    fn traverse(&self, curr: Coord, visited: &mut Option<Grid<bool>>) -> u64 {
+        if let Some(visited) = visited {
+            if visited[curr] {
+                return 0;
+            }
+
+            visited[curr] = true;
+        }
+
+        if self.map[curr] == 9 {
+            return 1;
+        }
+
+        let mut score = 0;
+        for next in self.next(curr) {
+            score += self.traverse(next, visited);
+        }
+
+        score
+    }
+}
+
+fn part_1(map: &TopologicalMap) -> u64 {
+    let starting_points = map.get_start();
+
+    let mut part_1_answer: u64 = 0;
+
+    for &start in &starting_points {
+        let visited = map.map.map(|_| false);
+        let score = map.traverse(start, &mut Some(visited));
+        part_1_answer += score;
+    }
+
+    part_1_answer
+}
+
+fn part_2(map: &TopologicalMap) -> u64 {
+    let starting_points = map.get_start();
+
+    let mut part_1_answer: u64 = 0;
+
+    for &start in &starting_points {
+        // the existance of this line of code is unsettling
+        let score = map.traverse(start, &mut None);
+        part_1_answer += score;
+    }
+
+    part_1_answer
+}
+
+pub struct Day10;
+pub const DAY: Day10 = Day10;
+impl Day for Day10 {
+    type Parsed = TopologicalMap;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<TopologicalMap, AocError> {
+        TopologicalMap::from_input(input)
+    }
+    fn try_part_1(&self, map: &TopologicalMap) -> Result<u64, AocError> {
+        Ok(part_1(map))
+    }
+    fn try_part_2(&mut self, map: &TopologicalMap) -> Result<u64, AocError> {
+        Ok(part_2(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
+}
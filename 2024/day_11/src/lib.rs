@@ -1,36 +1,6 @@
-use std::collections::linked_list::Iter;
-use std::collections::{HashMap, LinkedList};
-use std::time::Instant;
+use std::collections::HashMap;
 
-struct Ull<T> {
-    list: LinkedList<T>,
-}
-impl<T> Ull<T> {
-    fn new() -> Self {
-        let list = LinkedList::<T>::new();
-        Self { list }
-    }
-    fn push_back(&mut self, value: T) {
-        self.list.push_back(value);
-    }
-    fn insert(&mut self, idx: usize, val: T) {
-        let l = &mut self.list;
-
-        let mut tail = l.split_off(idx);
-        l.push_back(val);
-        l.append(&mut tail);
-    }
-
-    fn iter(&self) -> Iter<'_, T> {
-        self.list.iter()
-    }
-    fn len(&self) -> usize {
-        self.list.len()
-    }
-    fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
-        self.list.iter_mut().nth(idx)
-    }
-}
+use library::input::{AocError, Day};
 
 #[derive(Debug)]
 enum Blink {
@@ -77,6 +47,7 @@ impl Stone {
         }
     }
 
+    #[allow(dead_code)]
     fn blink_result(&self, count: u32) -> u64 {
         if count == 0 {
             return 0;
@@ -126,7 +97,7 @@ impl Stone {
     }
 }
 
-struct Stones {
+pub struct Stones {
     line: Vec<Stone>,
 }
 impl Stones {
@@ -145,35 +116,40 @@ impl Stones {
     fn blink(&self, count: u32) -> u64 {
         let mut res = 0;
         let mut cache = HashMap::new();
-        for (_, stone) in self.line.iter().enumerate() {
+        for stone in self.line.iter() {
             res += stone.blink_result_cache(count, &mut cache) + 1;
         }
         res
     }
 }
 
-fn part_1(input: &str) -> u64 {
-    let stones = Stones::from_input(input);
-
+fn part_1(stones: &Stones) -> u64 {
     stones.blink(25)
 }
 
-fn part_2(input: &str) -> u64 {
-    let stones = Stones::from_input(input);
-
+fn part_2(stones: &Stones) -> u64 {
     stones.blink(75)
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+pub struct Day11;
+pub const DAY: Day11 = Day11;
+impl Day for Day11 {
+    type Parsed = Stones;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    let start: Instant = Instant::now();
-    let part_1_answer = part_1(&input);
-    let duration = start.elapsed();
-    println!("Part 1 answer: {}, time: {:?}", part_1_answer, duration);
+    fn parse(&self, input: &str) -> Result<Stones, AocError> {
+        Ok(Stones::from_input(input))
+    }
+    fn try_part_1(&self, stones: &Stones) -> Result<u64, AocError> {
+        Ok(part_1(stones))
+    }
+    fn try_part_2(&mut self, stones: &Stones) -> Result<u64, AocError> {
+        Ok(part_2(stones))
+    }
+}
 
-    let start: Instant = Instant::now();
-    let part_2_answer = part_2(&input);
-    let duration = start.elapsed();
-    println!("Part 2 answer: {}, time: {:?}", part_2_answer, duration);
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
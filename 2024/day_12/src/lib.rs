@@ -1,15 +1,16 @@
 use std::{
     collections::{HashMap, HashSet},
     ops::Range,
-    time::Instant,
 };
 
 use library::grid::{Direction, UVec2};
+use library::input::{AocError, Day};
 type Coord = UVec2<usize>;
 
 #[derive(Debug)]
 struct GardenPlot {
     area: Vec<Coord>,
+    #[allow(dead_code)]
     plant_type: char,
 }
 impl GardenPlot {
@@ -20,7 +21,7 @@ impl GardenPlot {
         }
     }
     fn get_area(&self) -> u64 {
-        self.area.iter().count() as u64
+        self.area.len() as u64
     }
     fn get_perimeter(&self) -> u64 {
         let mut adjacent_sides = 0;
@@ -72,7 +73,7 @@ impl GardenPlot {
 }
 
 #[derive(Debug)]
-struct Garden {
+pub struct Garden {
     plots: Vec<GardenPlot>,
 }
 
@@ -127,9 +128,7 @@ impl Garden {
     }
 }
 
-fn part_1(input: &str) -> u64 {
-    let garden = Garden::from_input(input);
-
+fn part_1(garden: &Garden) -> u64 {
     let mut part_1_answer = 0;
 
     for plot in &garden.plots {
@@ -138,9 +137,7 @@ fn part_1(input: &str) -> u64 {
     part_1_answer
 }
 
-fn part_2(input: &str) -> u64 {
-    let garden = Garden::from_input(input);
-
+fn part_2(garden: &Garden) -> u64 {
     let mut part_2_answer = 0;
 
     for plot in &garden.plots {
@@ -200,16 +197,25 @@ fn part_2(input: &str) -> u64 {
     part_2_answer
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+pub struct Day12;
+pub const DAY: Day12 = Day12;
+impl Day for Day12 {
+    type Parsed = Garden;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    let start: Instant = Instant::now();
-    let part_1_answer = part_1(&input);
-    let duration = start.elapsed();
-    println!("Part 1 answer: {}, time: {:?}", part_1_answer, duration);
+    fn parse(&self, input: &str) -> Result<Garden, AocError> {
+        Ok(Garden::from_input(input))
+    }
+    fn try_part_1(&self, garden: &Garden) -> Result<u64, AocError> {
+        Ok(part_1(garden))
+    }
+    fn try_part_2(&mut self, garden: &Garden) -> Result<u64, AocError> {
+        Ok(part_2(garden))
+    }
+}
 
-    let start: Instant = Instant::now();
-    let part_2_answer = part_2(&input);
-    let duration = start.elapsed();
-    println!("Part 2 answer: {}, time: {:?}", part_2_answer, duration);
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
@@ -1,10 +1,9 @@
-use std::time::Instant;
-
 use library::grid::UVec2;
+use library::input::{AocError, Day};
 use regex::Regex;
 type Coord = UVec2<usize>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct ClawMachine {
     prize: Coord,
     button_a: Coord,
@@ -82,26 +81,33 @@ impl ClawMachine {
     }
 }
 
-struct Lobby {
+#[derive(Clone)]
+pub struct Lobby {
     claw_machines: Vec<ClawMachine>,
 }
 impl Lobby {
     fn from_input(input: &str) -> Self {
-        let claw_machines = input
-            .split("\r\n\r\n")
-            .map(|machine| ClawMachine::from_str(machine))
-            .collect();
+        let mut claw_machines = Vec::new();
+        let mut rest = input;
+        while let Some((machine, next)) = rest
+            .split_once("\r\n\r\n")
+            .or_else(|| rest.split_once("\n\n"))
+        {
+            claw_machines.push(ClawMachine::from_str(machine));
+            rest = next;
+        }
+        if !rest.trim().is_empty() {
+            claw_machines.push(ClawMachine::from_str(rest));
+        }
 
         Self { claw_machines }
     }
 }
 
-fn part_1(input: &str) -> u64 {
-    let lobby = Lobby::from_input(input);
-
+fn part_1(lobby: &Lobby) -> u64 {
     let mut part_1_answer = 0;
 
-    for machine in lobby.claw_machines {
+    for machine in &lobby.claw_machines {
         let Some((a, b)) = machine.get_button_presses() else {
             continue;
         };
@@ -110,8 +116,9 @@ fn part_1(input: &str) -> u64 {
     part_1_answer
 }
 
-fn part_2(input: &str) -> u64 {
-    let mut lobby = Lobby::from_input(input);
+fn part_2(lobby: &Lobby) -> u64 {
+    let mut lobby = lobby.clone();
+
     for machine in lobby.claw_machines.iter_mut() {
         machine.prize.x += 10000000000000;
         machine.prize.y += 10000000000000;
@@ -128,16 +135,25 @@ fn part_2(input: &str) -> u64 {
     part_1_answer
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+pub struct Day13;
+pub const DAY: Day13 = Day13;
+impl Day for Day13 {
+    type Parsed = Lobby;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    let start: Instant = Instant::now();
-    let part_1_answer = part_1(&input);
-    let duration = start.elapsed();
-    println!("Part 1 answer: {}, time: {:?}", part_1_answer, duration);
+    fn parse(&self, input: &str) -> Result<Lobby, AocError> {
+        Ok(Lobby::from_input(input))
+    }
+    fn try_part_1(&self, lobby: &Lobby) -> Result<u64, AocError> {
+        Ok(part_1(lobby))
+    }
+    fn try_part_2(&mut self, lobby: &Lobby) -> Result<u64, AocError> {
+        Ok(part_2(lobby))
+    }
+}
 
-    let start: Instant = Instant::now();
-    let part_2_answer = part_2(&input);
-    let duration = start.elapsed();
-    println!("Part 2 answer: {}, time: {:?}", part_2_answer, duration);
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
@@ -1,12 +1,13 @@
-use std::{ops::Rem, time::Instant};
+use std::ops::Rem;
 
 use itertools::Itertools;
-use library::grid::{Coord, UVec2};
+use library::grid::{Coord, Grid, Vec2};
+use library::input::{AocError, Day, InputType, Params};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Robot {
     position: Coord,
-    velocity: UVec2<i32>,
+    velocity: Vec2<i64>,
 }
 impl Robot {
     fn from_line(line: &str) -> Self {
@@ -38,48 +39,37 @@ impl Robot {
             panic!("Cannot parse pos y '{}'", vel_y);
         };
 
-        let velocity = UVec2::new(vel_x, vel_y);
+        let velocity = Vec2::new(vel_x, vel_y);
 
         Self { position, velocity }
     }
-
-    fn simulate(&mut self, width: usize, height: usize) {
-        let mut new_x = self.position.x as i32 + self.velocity.x;
-        if new_x < 0 {
-            new_x += width as i32;
-        } else if new_x >= width as i32 {
-            new_x %= width as i32;
-        }
-        self.position.x = new_x as usize;
-
-        let mut new_y = self.position.y as i32 + self.velocity.y;
-        if new_y < 0 {
-            new_y += height as i32;
-        } else if new_y >= height as i32 {
-            new_y %= height as i32;
-        }
-        self.position.y = new_y as usize;
-    }
 }
 
-struct Bathroom {
-    height: usize,
-    width: usize,
+#[derive(Clone)]
+pub struct Bathroom {
     robots: Vec<Robot>,
+    // only the size of the floor, the robots wrap around its edges
+    floor: Grid<()>,
 }
 impl Bathroom {
     fn from_input(input: &str, width: usize, height: usize) -> Self {
-        let robots = input.lines().map(|line| Robot::from_line(line)).collect();
+        let robots = input.lines().map(Robot::from_line).collect();
 
         Self {
-            height,
-            width,
             robots,
+            floor: Grid::new(width, height, ()),
         }
     }
+    fn width(&self) -> usize {
+        self.floor.width()
+    }
+    fn height(&self) -> usize {
+        self.floor.height()
+    }
     fn simulate(&mut self) {
+        let floor = self.floor.wrapping();
         for robot in self.robots.iter_mut() {
-            robot.simulate(self.width, self.height);
+            robot.position = floor.step(robot.position, robot.velocity);
         }
     }
 
@@ -99,14 +89,14 @@ impl Bathroom {
 
         inside.count()
     }
+    #[allow(dead_code)]
     fn debug(&self) {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let count = self
-                    .robots
-                    .iter()
-                    .filter(|robot| robot.position.x == x && robot.position.y == y)
-                    .count();
+        let mut counts = Grid::new(self.width(), self.height(), 0);
+        for robot in &self.robots {
+            counts[robot.position] += 1;
+        }
+        for row in counts.rows() {
+            for &count in row {
                 if count == 0 {
                     print!(".")
                 } else {
@@ -127,35 +117,38 @@ impl Bathroom {
             variance_x += robot1.position.x.abs_diff(robot2.position.x) as u32;
             variance_y += robot1.position.y.abs_diff(robot2.position.y) as u32;
         }
-        return (variance_x, variance_y);
+        (variance_x, variance_y)
     }
-}
+    fn safety_factor(&self) -> u64 {
+        let quarter_width = self.width().div_euclid(2);
+        let quarter_2_start_x = self.width().div_ceil(2);
 
-fn part_1(input: &str) -> u64 {
-    let mut bathroom = Bathroom::from_input(input, 101, 103);
+        let quarter_height = self.height().div_euclid(2);
+        let quarter_2_start_y = self.height().div_ceil(2);
 
-    bathroom.simulate_n(100);
+        let mut safety_factor = 1;
 
-    let quarter_width = bathroom.width.div_euclid(2);
-    let quarter_2_start_x = bathroom.width.div_ceil(2);
+        for (y_min, y_max) in [(0, quarter_height), (quarter_2_start_y, self.height())] {
+            for (x_min, x_max) in [(0, quarter_width), (quarter_2_start_x, self.width())] {
+                let quarter_count = self.num_robots_in_area(x_min, x_max, y_min, y_max);
+                safety_factor *= quarter_count;
+            }
+        }
 
-    let quarter_height = bathroom.height.div_euclid(2);
-    let quarter_2_start_y = bathroom.height.div_ceil(2);
+        safety_factor as u64
+    }
+}
 
-    let mut part_1_answer = 1;
+fn part_1(bathroom: &Bathroom) -> u64 {
+    let mut bathroom = bathroom.clone();
 
-    for (y_min, y_max) in [(0, quarter_height), (quarter_2_start_y, bathroom.height)] {
-        for (x_min, x_max) in [(0, quarter_width), (quarter_2_start_x, bathroom.width)] {
-            let quarter_count = bathroom.num_robots_in_area(x_min, x_max, y_min, y_max);
-            part_1_answer *= quarter_count;
-        }
-    }
+    bathroom.simulate_n(100);
 
-    part_1_answer as u64
+    bathroom.safety_factor()
 }
 
-fn part_2(input: &str) -> u32 {
-    let mut bathroom = Bathroom::from_input(input, 101, 103);
+fn part_2(bathroom: &Bathroom) -> u32 {
+    let mut bathroom = bathroom.clone();
 
     let mut min_variances = (u32::MAX, u32::MAX);
     let mut min_variances_i = (0, 0);
@@ -174,42 +167,60 @@ fn part_2(input: &str) -> u32 {
         bathroom.simulate();
     }
 
-    let diff_var = min_variances_i.0 as i32 - min_variances_i.1 as i32;
+    let diff_var = min_variances_i.0 - min_variances_i.1;
 
-    let diff_wh = bathroom.width as i32 - bathroom.height as i32;
+    let diff_wh = bathroom.width() as i32 - bathroom.height() as i32;
 
     let n = diff_var / diff_wh;
 
-    (bathroom.width as i32 * n + 52).rem(bathroom.width as i32 * bathroom.height as i32) as u32
-
+    (bathroom.width() as i32 * n + 52).rem(bathroom.width() as i32 * bathroom.height() as i32)
+        as u32
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-
-    let start: Instant = Instant::now();
-    let part_1_answer = part_1(&input);
-    let duration = start.elapsed();
-    println!("Part 1 answer: {}, time: {:?}", part_1_answer, duration);
-
-    let start: Instant = Instant::now();
-    let part_2_answer = part_2(&input);
-    let duration = start.elapsed();
-    println!("Part 2 answer: {}, time: {:?}", part_2_answer, duration);
+pub struct Day14 {
+    width: usize,
+    height: usize,
+}
+pub const DAY: Day14 = Day14 {
+    width: 101,
+    height: 103,
+};
+impl Day for Day14 {
+    type Parsed = Bathroom;
+    type Part1 = u64;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Bathroom, AocError> {
+        Ok(Bathroom::from_input(input, self.width, self.height))
+    }
+    fn select_params(&mut self, input_type: &InputType) {
+        *self = if input_type.is_example() {
+            Day14 {
+                width: 11,
+                height: 7,
+            }
+        } else {
+            DAY
+        };
+    }
+    fn configure(&mut self, params: &mut Params) -> Result<(), AocError> {
+        if let Some(width) = params.take("width")? {
+            self.width = width;
+        }
+        if let Some(height) = params.take("height")? {
+            self.height = height;
+        }
+        Ok(())
+    }
+    fn try_part_1(&self, bathroom: &Bathroom) -> Result<u64, AocError> {
+        Ok(part_1(bathroom))
+    }
+    fn try_part_2(&mut self, bathroom: &Bathroom) -> Result<u32, AocError> {
+        Ok(part_2(bathroom))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Bathroom;
-
-    #[test]
-    fn test1() {
-        let input = include_str!("../example2.txt");
-        let bathroom = Bathroom::from_input(input, 11, 7);
-
-        bathroom.debug();
-
-        let result = bathroom.variance();
-        assert_eq!(result, true);
-    }
+    library::input::examples!(crate::DAY);
 }
@@ -1,11 +1,11 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 
 fn valid_increment(increment: i64, is_ascending: bool) -> bool {
     is_ascending == (increment > 0) && increment.abs() <= 3 && increment.abs() > 0
 }
 
 #[derive(Debug)]
-struct Report {
+pub struct Report {
     levels: Vec<u32>,
 }
 impl Report {
@@ -104,28 +104,31 @@ impl Report {
     }
 }
 
-type Reports = Vec<Report>;
+pub type Reports = Vec<Report>;
 
 fn reports_from_str(input: &str) -> Reports {
     input.lines().map(Report::from_line).collect()
 }
 
-struct Day2;
-const DAY: Day2 = Day2;
-impl Day<u32> for Day2 {
-    fn part_1(&self, input: &str) -> u32 {
-        let reports = reports_from_str(input);
+pub struct Day2;
+pub const DAY: Day2 = Day2;
+impl Day for Day2 {
+    type Parsed = Reports;
+    type Part1 = u32;
+    type Part2 = u32;
 
-        reports
+    fn parse(&self, input: &str) -> Result<Reports, AocError> {
+        Ok(reports_from_str(input))
+    }
+    fn try_part_1(&self, reports: &Reports) -> Result<u32, AocError> {
+        Ok(reports
             .iter()
             .map(Report::is_valid)
             .map(|is_valid| if is_valid { 1 } else { 0 })
-            .sum()
+            .sum())
     }
-    fn part_2(&self, input: &str) -> u32 {
-        let reports = reports_from_str(input);
-
-        reports
+    fn try_part_2(&mut self, reports: &Reports) -> Result<u32, AocError> {
+        Ok(reports
             .iter()
             .map(|report| {
                 report.is_valid()
@@ -135,10 +138,11 @@ impl Day<u32> for Day2 {
                     || report.is_valid_part_2(false, true)
             })
             .map(|is_valid| if is_valid { 1 } else { 0 })
-            .sum()
+            .sum())
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
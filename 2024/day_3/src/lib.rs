@@ -1,4 +1,4 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use regex::Regex;
 
 #[derive(Debug)]
@@ -8,9 +8,7 @@ enum Toggle {
 }
 impl Toggle {
     fn parse(str: &str) -> Option<Self> {
-        let Some(t) = str.split_once("(") else {
-            return None;
-        };
+        let t = str.split_once("(")?;
 
         let num_arguments = t.1.chars().filter(|c| *c == ',').count();
 
@@ -29,7 +27,7 @@ impl Toggle {
         };
         let regex = format!(r"{instruction_name}\({argument_regex}\)");
 
-         let regex = Regex::new(&regex).unwrap();
+        let regex = Regex::new(&regex).unwrap();
         if regex.captures_len() != num_arguments + 1 {
             return None;
         }
@@ -70,17 +68,25 @@ impl Multiply {
     }
 }
 
-struct Day3;
-const DAY: Day3 = Day3;
-impl Day<u32> for Day3 {
-    fn part_1(&self, input: &str) -> u32 {
-        input
+pub struct Day3;
+pub const DAY: Day3 = Day3;
+impl Day for Day3 {
+    // the corrupted memory is scanned as text
+    type Parsed = String;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<String, AocError> {
+        Ok(input.to_string())
+    }
+    fn try_part_1(&self, input: &String) -> Result<u32, AocError> {
+        Ok(input
             .split_inclusive(")")
             .filter_map(Multiply::parse)
             .map(Multiply::compute)
-            .sum()
+            .sum())
     }
-    fn part_2(&self, input: &str) -> u32 {
+    fn try_part_2(&mut self, input: &String) -> Result<u32, AocError> {
         let mut active = true;
         let mut part_2_answer = 0;
 
@@ -105,10 +111,11 @@ impl Day<u32> for Day3 {
                 }
             }
         }
-        part_2_answer
+        Ok(part_2_answer)
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
@@ -0,0 +1,97 @@
+use library::grid::{Coord, Direction8, Grid};
+use library::input::{AocError, Day};
+
+pub struct WordSearch {
+    text: Grid<char>,
+}
+impl WordSearch {
+    fn from_input(input: &str) -> Result<Self, AocError> {
+        let text = Grid::parse(input, |c| c)?;
+
+        Ok(Self { text })
+    }
+
+    fn find_word_count(&self, word: &str) -> u32 {
+        let Some(start) = word.chars().next() else {
+            return 0;
+        };
+
+        let mut count = 0;
+        for point in self.text.find_all(&start) {
+            for direction in Direction8::ALL {
+                if self.check_word(word, point, direction) {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    fn check_word(&self, word: &str, start: Coord, direction: Direction8) -> bool {
+        let read: String = self
+            .text
+            .ray(start, direction)
+            .take(word.len())
+            .map(|coord| self.text[coord])
+            .collect();
+
+        read == word
+    }
+
+    // the letters on either side of `centre` along the diagonal through `direction`
+    fn diagonal(&self, centre: Coord, direction: Direction8) -> Option<[char; 2]> {
+        let first = (centre + direction).ok()?;
+        let second = (centre + direction.inverse()).ok()?;
+
+        Some([*self.text.get(first)?, *self.text.get(second)?])
+    }
+
+    // an `A` with `MAS` written forwards or backwards on both diagonals
+    fn is_x_mas(&self, centre: Coord) -> bool {
+        [Direction8::NorthEast, Direction8::SouthEast]
+            .into_iter()
+            .all(|direction| {
+                matches!(
+                    self.diagonal(centre, direction),
+                    Some(['M', 'S'] | ['S', 'M'])
+                )
+            })
+    }
+}
+
+fn part_1(word_search: &WordSearch) -> u32 {
+    word_search.find_word_count("XMAS")
+}
+
+fn part_2(word_search: &WordSearch) -> u32 {
+    word_search
+        .text
+        .find_all(&'A')
+        .into_iter()
+        .filter(|&centre| word_search.is_x_mas(centre))
+        .count() as u32
+}
+
+pub struct Day4;
+pub const DAY: Day4 = Day4;
+impl Day for Day4 {
+    type Parsed = WordSearch;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<WordSearch, AocError> {
+        WordSearch::from_input(input)
+    }
+    fn try_part_1(&self, word_search: &WordSearch) -> Result<u32, AocError> {
+        Ok(part_1(word_search))
+    }
+    fn try_part_2(&mut self, word_search: &WordSearch) -> Result<u32, AocError> {
+        Ok(part_2(word_search))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
+}
@@ -0,0 +1,179 @@
+use library::input::{AocError, Day};
+
+#[derive(Debug, Clone)]
+struct OrderRule {
+    rule: [u32; 2],
+}
+impl OrderRule {
+    fn from_line(line: &str) -> Result<Self, AocError> {
+        let Some((first, second)) = line.split_once("|") else {
+            return Err(AocError::new(format!("Expected rule 'X|Y', got '{line}'")));
+        };
+        let Some(first) = first.parse().ok() else {
+            return Err(AocError::new(format!("Cannot parse page '{first}'")).at_column(1));
+        };
+        let Some(second) = second.parse().ok() else {
+            return Err(AocError::new(format!("Cannot parse page '{second}'"))
+                .at_column(line.len() - second.len() + 1));
+        };
+        Ok(Self {
+            rule: [first, second],
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PageOrder {
+    order: Vec<u32>,
+}
+impl PageOrder {
+    fn from_line(line: &str) -> Result<Self, AocError> {
+        let mut order = Vec::new();
+        let mut column = 1;
+
+        for str in line.split(",") {
+            let Ok(page) = str.parse() else {
+                return Err(AocError::new(format!("Cannot parse page '{str}'")).at_column(column));
+            };
+            order.push(page);
+            column += str.len() + 1;
+        }
+        Ok(Self { order })
+    }
+
+    fn get_rule_idx(&self, order_rule: &OrderRule) -> Option<(usize, usize)> {
+        let page_order = &self.order;
+        let first = order_rule.rule[0];
+        let first_idx = page_order.iter().position(|x| x == &first)?;
+
+        let second = order_rule.rule[1];
+        let second_idx = page_order.iter().position(|x| x == &second)?;
+
+        Some((first_idx, second_idx))
+    }
+
+    fn check_rule(&self, order_rule: &OrderRule) -> bool {
+        let Some((first_idx, second_idx)) = self.get_rule_idx(order_rule) else {
+            return true;
+        };
+
+        first_idx < second_idx
+    }
+
+    fn check_order(&self, order_rules: &Vec<OrderRule>) -> bool {
+        for order_rule in order_rules {
+            if !self.check_rule(order_rule) {
+                return false;
+            }
+        }
+        true
+    }
+    fn fix_order(&mut self, order_rules: &Vec<OrderRule>) {
+        while !self.check_order(order_rules) {
+            for order_rule in order_rules {
+                if !self.check_rule(order_rule) {
+                    let Some((first_idx, second_idx)) = self.get_rule_idx(order_rule) else {
+                        continue;
+                    };
+                    // print!("{:?}\t-->\t", self);
+                    self.order.swap(first_idx, second_idx);
+                    // println!("{:?}", self);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PrintJob {
+    order_rules: Vec<OrderRule>,
+    page_orders: Vec<PageOrder>,
+}
+
+impl PrintJob {
+    fn from_input(input: &str) -> Result<Self, AocError> {
+        let Some((rules_str, page_orders_str)) = input
+            .split_once("\r\n\r\n")
+            .or_else(|| input.split_once("\n\n"))
+        else {
+            return Err(AocError::new(
+                "Cannot seperate rules from page order, expected a blank line",
+            ));
+        };
+
+        let order_rules = AocError::parse_lines(rules_str, OrderRule::from_line)?;
+        let page_orders = AocError::parse_lines(page_orders_str, PageOrder::from_line)
+            .map_err(|e| e.after_lines(rules_str.lines().count() + 1))?;
+
+        Ok(Self {
+            order_rules,
+            page_orders,
+        })
+    }
+}
+
+fn part_1(print_job: &PrintJob) -> u32 {
+    let mut part_1_answer = 0;
+
+    for page_order in &print_job.page_orders {
+        if !page_order.check_order(&print_job.order_rules) {
+            continue;
+        }
+
+        let add_idx = page_order.order.len().div_ceil(2) - 1;
+        part_1_answer += page_order.order[add_idx];
+    }
+    part_1_answer
+}
+
+fn part_2(print_job: &PrintJob) -> u32 {
+    let mut part_2_answer = 0;
+
+    for page_order in &print_job.page_orders {
+        if page_order.check_order(&print_job.order_rules) {
+            continue;
+        }
+        let mut page_order = page_order.clone();
+
+        page_order.fix_order(&print_job.order_rules);
+
+        let add_idx = page_order.order.len().div_ceil(2) - 1;
+        part_2_answer += page_order.order[add_idx];
+    }
+    part_2_answer
+}
+
+pub struct Day5;
+pub const DAY: Day5 = Day5;
+impl Day for Day5 {
+    type Parsed = PrintJob;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<PrintJob, AocError> {
+        PrintJob::from_input(input)
+    }
+    fn try_part_1(&self, print_job: &PrintJob) -> Result<u32, AocError> {
+        Ok(part_1(print_job))
+    }
+    fn try_part_2(&mut self, print_job: &PrintJob) -> Result<u32, AocError> {
+        Ok(part_2(print_job))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DAY;
+    use library::input::Day;
+
+    library::input::examples!(crate::DAY);
+
+    #[test]
+    fn test_malformed_page_order() {
+        let input = "47|53\n97|13\n\n75,47\n75,x,53\n";
+        let error = DAY.parse(input).unwrap_err();
+
+        assert_eq!(error.line, Some(5));
+        assert_eq!(error.column, Some(4));
+    }
+}
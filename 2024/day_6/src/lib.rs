@@ -1,8 +1,7 @@
-use colorize::AnsiColor;
+use colored::Colorize;
 use core::fmt;
-use library::grid::{Direction, UVec2};
-use std::time::Instant;
-type Coord = UVec2<usize>;
+use library::grid::{Coord, Direction, Grid};
+use library::input::{AocError, Day};
 
 #[derive(PartialEq, Clone)]
 enum Tile {
@@ -35,62 +34,45 @@ impl fmt::Display for Tile {
 }
 
 #[derive(Clone)]
-struct Lab {
-    grid: Vec<Vec<Tile>>,
-    width: usize,
-    height: usize,
+pub struct Lab {
+    grid: Grid<Tile>,
 }
 impl Lab {
-    fn from_input(input: &str) -> Self {
-        let grid: Vec<Vec<Tile>> = input
-            .lines()
-            .map(|line| line.chars().map(|c| Tile::from_char(c)).collect())
-            .collect();
-        let width = grid.len();
-        let height = grid[0].len();
+    fn from_input(input: &str) -> Result<Self, AocError> {
+        let grid = Grid::parse(input, Tile::from_char)?;
 
-        Self {
-            grid,
-            width,
-            height,
-        }
+        Ok(Self { grid })
+    }
+
+    fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    fn height(&self) -> usize {
+        self.grid.height()
     }
 
     fn clone_with_wall(&self, coords: &Coord) -> Self {
         let mut clone: Lab = self.clone();
 
-        clone.grid[coords.y][coords.x] = Tile::Obstruction;
+        clone.grid[*coords] = Tile::Obstruction;
         clone
     }
 
     fn get_start(&self) -> Coord {
-        let Some(start_y) = self
-            .grid
-            .iter()
-            .position(|line| line.contains(&Tile::Start))
-        else {
-            panic!("Cannot find start y position");
-        };
-
-        let Some(start_x) = self.grid[start_y].iter().position(|c| c == &Tile::Start) else {
-            panic!("Cannot find start x position");
-        };
-
-        Coord::new(start_x, start_y)
+        self.grid
+            .find(&Tile::Start)
+            .expect("Cannot find start position")
     }
 
     fn get_tile(&self, coords: &Coord) -> &Tile {
-        &self.grid[coords.y][coords.x]
+        &self.grid[*coords]
     }
 
     fn next(&self, direction: Direction, current: &Coord) -> Option<Coord> {
         let next = (*current + direction).ok()?;
 
-        if next.check_bounds(self.width, self.height) {
-            return None;
-        }
-
-        if self.get_tile(&next) == &Tile::Obstruction {
+        if self.grid.get(next)? == &Tile::Obstruction {
             return None;
         }
 
@@ -98,31 +80,24 @@ impl Lab {
     }
 
     fn is_exit(&self, current: &Coord, direction: &Direction) -> bool {
-        if current.x == 0 && direction == &Direction::West {
-            true
-        } else if current.x + 1 == self.width && direction == &Direction::East {
-            true
-        } else if current.y == 0 && direction == &Direction::North {
-            true
-        } else if current.y + 1 == self.height && direction == &Direction::South {
-            true
-        } else {
-            false
-        }
+        (current.x == 0 && direction == &Direction::West)
+            || (current.x + 1 == self.width() && direction == &Direction::East)
+            || (current.y == 0 && direction == &Direction::North)
+            || (current.y + 1 == self.height() && direction == &Direction::South)
     }
 
-    fn check_loop(&self, current: &Coord, visited: &Vec<Vec<Visited>>) -> bool {
+    fn check_loop(&self, _current: &Coord, _visited: &Grid<Visited>) -> bool {
         let mut direction = Direction::North;
         let mut current = self.get_start();
-        let mut visited = vec![vec![Visited::init(); self.width]; self.height];
+        let mut visited = self.grid.map(|_| Visited::init());
 
         loop {
-            if visited[current.y][current.x].get(&direction) {
+            if visited[current].get(&direction) {
                 // self.debug_2(&current, &visited);
 
                 return true;
             }
-            visited[current.y][current.x].set(&direction);
+            visited[current].set(&direction);
             // check if exit
             if self.is_exit(&current, &direction) {
                 return false;
@@ -139,15 +114,15 @@ impl Lab {
         }
     }
 
-    fn debug(&self, current: &Coord, visited: &Vec<Vec<Visited>>, loop_wall: Vec<Vec<bool>>) {
-        for (y, line) in visited.iter().enumerate() {
+    fn debug(&self, _current: &Coord, visited: &Grid<Visited>, loop_wall: Grid<bool>) {
+        for (y, line) in visited.rows().enumerate() {
             for (x, v) in line.iter().enumerate() {
                 let tile = self.get_tile(&Coord::new(x, y));
                 let tile = format!("{}", tile);
                 // if current.x == x && current.y == y {
                 // print!("X");
                 // } else
-                if loop_wall[y][x] {
+                if loop_wall[Coord::new(x, y)] {
                     print!("{}", tile.green());
                 } else if v.visited() {
                     print!("{}", v);
@@ -159,8 +134,9 @@ impl Lab {
         }
         println!();
     }
-    fn debug_2(&self, current: &Coord, visited: &Vec<Vec<Visited>>) {
-        for (y, line) in visited.iter().enumerate() {
+    #[allow(dead_code)]
+    fn debug_2(&self, current: &Coord, visited: &Grid<Visited>) {
+        for (y, line) in visited.rows().enumerate() {
             for (x, v) in line.iter().enumerate() {
                 let tile = self.get_tile(&Coord::new(x, y));
                 let tile = format!("{}", tile);
@@ -178,18 +154,16 @@ impl Lab {
     }
 }
 
-fn part_1(input: &str) -> u32 {
-    let lab = Lab::from_input(input);
-
+fn part_1(lab: &Lab) -> u32 {
     let start = lab.get_start();
 
-    let mut visited: Vec<Vec<bool>> = vec![vec![false; lab.width]; lab.height];
+    let mut visited = lab.grid.map(|_| false);
 
     let mut direction = Direction::North;
     let mut current = start;
 
     loop {
-        visited[current.y][current.x] = true;
+        visited[current] = true;
         // check if exit
         if lab.is_exit(&current, &direction) {
             break;
@@ -205,7 +179,7 @@ fn part_1(input: &str) -> u32 {
         }
     }
 
-    let visited = visited.iter().flatten().filter(|v| **v);
+    let visited = visited.iter().filter(|(_, v)| **v);
 
     visited.count() as u32
 }
@@ -278,13 +252,11 @@ impl Visited {
     }
 }
 
-fn part_2(input: &str) -> u32 {
-    let lab = Lab::from_input(input);
-
+fn part_2(lab: &Lab) -> u32 {
     let start = lab.get_start();
 
-    let mut visited = vec![vec![Visited::init(); lab.width]; lab.height];
-    let mut wall = vec![vec![false; lab.width]; lab.height];
+    let mut visited = lab.grid.map(|_| Visited::init());
+    let mut wall = lab.grid.map(|_| false);
 
     let mut direction = Direction::North;
     let mut current = start;
@@ -292,19 +264,19 @@ fn part_2(input: &str) -> u32 {
     let mut part_2_answer = 0;
 
     loop {
-        if !wall[current.y][current.x] && lab.get_tile(&current) == &Tile::Floor {
+        if !wall[current] && lab.get_tile(&current) == &Tile::Floor {
             let add_wall = lab.clone_with_wall(&current);
 
             if add_wall.check_loop(&current, &visited) {
                 part_2_answer += 1;
-                wall[current.y][current.x] = true;
+                wall[current] = true;
             }
         }
 
-        if visited[current.y][current.x].get(&direction) {
+        if visited[current].get(&direction) {
             panic!("in a loop");
         }
-        visited[current.y][current.x].set(&direction);
+        visited[current].set(&direction);
         // check if exit
         if lab.is_exit(&current, &direction) {
             break;
@@ -322,7 +294,7 @@ fn part_2(input: &str) -> u32 {
 
     lab.debug(&current, &visited, wall.clone());
 
-    let visited = wall.iter().flatten().filter(|v| **v);
+    let visited = wall.iter().filter(|(_, v)| **v);
 
     let visited = visited.count() as u32;
     println!("Wall: {:}", visited);
@@ -330,16 +302,25 @@ fn part_2(input: &str) -> u32 {
     part_2_answer
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+pub struct Day6;
+pub const DAY: Day6 = Day6;
+impl Day for Day6 {
+    type Parsed = Lab;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    let start: Instant = Instant::now();
-    let part_1_answer = part_1(&input);
-    let duration = start.elapsed();
-    println!("Part 1 answer: {}, time: {:?}", part_1_answer, duration);
+    fn parse(&self, input: &str) -> Result<Lab, AocError> {
+        Lab::from_input(input)
+    }
+    fn try_part_1(&self, lab: &Lab) -> Result<u32, AocError> {
+        Ok(part_1(lab))
+    }
+    fn try_part_2(&mut self, lab: &Lab) -> Result<u32, AocError> {
+        Ok(part_2(lab))
+    }
+}
 
-    let start: Instant = Instant::now();
-    let part_2_answer = part_2(&input);
-    let duration = start.elapsed();
-    println!("Part 2 answer: {}, time: {:?}", part_2_answer, duration);
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
@@ -1,4 +1,6 @@
-use std::{slice::Iter, time::Instant};
+use std::slice::Iter;
+
+use library::input::{AocError, Day};
 
 enum Operator {
     Add,
@@ -20,7 +22,7 @@ impl Operator {
 }
 
 #[derive(Debug)]
-struct Equation {
+pub struct Equation {
     test_value: u64,
     operands: Vec<u64>,
 }
@@ -73,67 +75,55 @@ fn check_equation(
         }
     }
 
-    return false;
+    false
 }
 
-struct Calibrations {
-    equations: Vec<Equation>,
-    operators: Vec<Operator>,
+fn parse_equations(input: &str) -> Vec<Equation> {
+    input.lines().map(Equation::from_line).collect()
 }
 
-impl Calibrations {
-    fn from_input(input: &str, operators: Vec<Operator>) -> Self {
-        let equations = input
-            .lines()
-            .map(|line| Equation::from_line(line))
-            .collect();
-        Self {
-            equations,
-            operators,
-        }
-    }
-}
-
-fn part_1(input: &str) -> u64 {
+fn part_1(equations: &[Equation]) -> u64 {
     let operators = vec![Operator::Add, Operator::Mul];
-    let calibrations = Calibrations::from_input(input, operators);
 
-    calibrations
-        .equations
+    equations
         .iter()
-        .filter(|eq| eq.check_equation(&calibrations.operators))
+        .filter(|eq| eq.check_equation(&operators))
         .fold(0, |acc, eq| acc + eq.test_value)
 }
 
-fn part_2(input: &str) -> u64 {
+fn part_2(equations: &[Equation]) -> u64 {
     let operators = vec![Operator::Add, Operator::Mul, Operator::Concat];
-    let calibrations = Calibrations::from_input(input, operators);
 
-    calibrations
-        .equations
+    equations
         .iter()
-        .filter(|eq| eq.check_equation(&calibrations.operators))
+        .filter(|eq| eq.check_equation(&operators))
         .fold(0, |acc, eq| acc + eq.test_value)
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-
-    let start: Instant = Instant::now();
-    let part_1_answer = part_1(&input);
-    let duration = start.elapsed();
-    println!("Part 1 answer: {}, time: {:?}", part_1_answer, duration);
+pub struct Day7;
+pub const DAY: Day7 = Day7;
+impl Day for Day7 {
+    type Parsed = Vec<Equation>;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    let start: Instant = Instant::now();
-    let part_2_answer = part_2(&input);
-    let duration = start.elapsed();
-    println!("Part 2 answer: {}, time: {:?}", part_2_answer, duration);
+    fn parse(&self, input: &str) -> Result<Vec<Equation>, AocError> {
+        Ok(parse_equations(input))
+    }
+    fn try_part_1(&self, equations: &Vec<Equation>) -> Result<u64, AocError> {
+        Ok(part_1(equations))
+    }
+    fn try_part_2(&mut self, equations: &Vec<Equation>) -> Result<u64, AocError> {
+        Ok(part_2(equations))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::Operator;
 
+    library::input::examples!(crate::DAY);
+
     #[test]
     fn test1() {
         let result = Operator::Concat.apply(10, 10);
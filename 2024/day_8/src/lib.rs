@@ -1,8 +1,8 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
 use itertools::Itertools;
-use library::grid::{find_in_coord, UVec2};
-type Coord = UVec2<usize>;
+use library::grid::{Coord, Grid};
+use library::input::{AocError, Day};
 
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
 enum Tile {
@@ -18,29 +18,18 @@ impl Tile {
     }
 }
 
-struct Roof {
-    tiles: Vec<Vec<Tile>>,
-    width: usize,
-    height: usize,
+pub struct Roof {
+    tiles: Grid<Tile>,
 }
 
 impl Roof {
-    fn from_input(input: &str) -> Self {
-        let tiles: Vec<Vec<Tile>> = input
-            .lines()
-            .map(|line| line.chars().map(|c| Tile::from_char(c)).collect())
-            .collect();
-        let height = tiles.len();
-        let width = tiles[0].len();
-
-        Self {
-            tiles,
-            width,
-            height,
-        }
+    fn from_input(input: &str) -> Result<Self, AocError> {
+        let tiles = Grid::parse(input, Tile::from_char)?;
+
+        Ok(Self { tiles })
     }
     fn get_antennas(&self) -> Vec<&Tile> {
-        let mut tiles: Vec<&Tile> = self.tiles.iter().flatten().collect();
+        let mut tiles: Vec<&Tile> = self.tiles.iter().map(|(_, tile)| tile).collect();
         tiles.sort();
         tiles.dedup();
 
@@ -48,25 +37,7 @@ impl Roof {
     }
 
     fn get_antenna_coords(&self, find_tile: &Tile) -> Vec<Coord> {
-        // let tiles: Vec<(Coord, &Tile)> = self
-        //     .tiles
-        //     .iter()
-        //     .enumerate()
-        //     .map(|(y, line)| {
-        //         line.iter()
-        //             .enumerate()
-        //             .map(|(x, tile)| (Coord::new(x, y), tile))
-        //             .collect::<Vec<(Coord, &Tile)>>()
-        //     })
-        //     .flatten()
-        //     .collect();
-
-        // tiles
-        //     .iter()
-        //     .filter(|(_, tile)| tile == &find_tile)
-        //     .map(|(coord, _)| *coord)
-        //     .collect()
-        find_in_vec2(&self.tiles, find_tile)
+        self.tiles.find_all(find_tile)
     }
 }
 
@@ -91,9 +62,7 @@ fn calculate_annode_part_1(
     Some(annode_coord)
 }
 
-fn part_1(input: &str) -> u64 {
-    let roof = Roof::from_input(input);
-
+fn part_1(roof: &Roof) -> u64 {
     let antennas = roof.get_antennas();
 
     let mut annodes: HashMap<Coord, bool> = HashMap::new();
@@ -105,17 +74,15 @@ fn part_1(input: &str) -> u64 {
             let coord1 = comb_coords[0];
             let coord2 = comb_coords[1];
 
-            match calculate_annode_part_1(coord1, coord2, roof.width, roof.height) {
-                Some(coord) => {
-                    annodes.insert(coord, true);
-                }
-                None => (),
+            if let Some(coord) =
+                calculate_annode_part_1(coord1, coord2, roof.tiles.width(), roof.tiles.height())
+            {
+                annodes.insert(coord, true);
             }
-            match calculate_annode_part_1(coord2, coord1, roof.width, roof.height) {
-                Some(coord) => {
-                    annodes.insert(coord, true);
-                }
-                None => (),
+            if let Some(coord) =
+                calculate_annode_part_1(coord2, coord1, roof.tiles.width(), roof.tiles.height())
+            {
+                annodes.insert(coord, true);
             }
         }
     }
@@ -146,10 +113,7 @@ fn calculate_annode_part_2(
     let mut annodes: Vec<Coord> = vec![*tower, *resonator];
     let mut check_coord = *tower;
 
-    loop {
-        let Some(annode) = offset_coord(&check_coord, (diff_x, diff_y)) else {
-            break;
-        };
+    while let Some(annode) = offset_coord(&check_coord, (diff_x, diff_y)) {
         if annode.check_bounds(max_x, max_y) {
             break;
         }
@@ -160,9 +124,7 @@ fn calculate_annode_part_2(
     annodes
 }
 
-fn part_2(input: &str) -> u64 {
-    let roof = Roof::from_input(input);
-
+fn part_2(roof: &Roof) -> u64 {
     let antennas = roof.get_antennas();
 
     let mut annodes: HashMap<Coord, bool> = HashMap::new();
@@ -174,11 +136,15 @@ fn part_2(input: &str) -> u64 {
             let coord1 = comb_coords[0];
             let coord2 = comb_coords[1];
 
-            for annode in calculate_annode_part_2(coord1, coord2, roof.width, roof.height) {
+            for annode in
+                calculate_annode_part_2(coord1, coord2, roof.tiles.width(), roof.tiles.height())
+            {
                 annodes.insert(annode, true);
             }
 
-            for annode in calculate_annode_part_2(coord2, coord1, roof.width, roof.height) {
+            for annode in
+                calculate_annode_part_2(coord2, coord1, roof.tiles.width(), roof.tiles.height())
+            {
                 annodes.insert(annode, true);
             }
         }
@@ -187,16 +153,25 @@ fn part_2(input: &str) -> u64 {
     annodes.keys().len() as u64
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+pub struct Day8;
+pub const DAY: Day8 = Day8;
+impl Day for Day8 {
+    type Parsed = Roof;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    let start: Instant = Instant::now();
-    let part_1_answer = part_1(&input);
-    let duration = start.elapsed();
-    println!("Part 1 answer: {}, time: {:?}", part_1_answer, duration);
+    fn parse(&self, input: &str) -> Result<Roof, AocError> {
+        Roof::from_input(input)
+    }
+    fn try_part_1(&self, roof: &Roof) -> Result<u64, AocError> {
+        Ok(part_1(roof))
+    }
+    fn try_part_2(&mut self, roof: &Roof) -> Result<u64, AocError> {
+        Ok(part_2(roof))
+    }
+}
 
-    let start: Instant = Instant::now();
-    let part_2_answer = part_2(&input);
-    let duration = start.elapsed();
-    println!("Part 2 answer: {}, time: {:?}", part_2_answer, duration);
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
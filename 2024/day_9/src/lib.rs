@@ -1,4 +1,6 @@
-use std::{fmt, time::Instant};
+use std::fmt;
+
+use library::input::{AocError, Day};
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 enum Content {
@@ -8,7 +10,7 @@ enum Content {
 impl Content {
     fn format(&self) -> String {
         match self {
-            Self::Empty => format!("."),
+            Self::Empty => ".".to_string(),
             Self::File(c) => format!("{c}"),
         }
     }
@@ -35,7 +37,7 @@ impl File {
 }
 
 #[derive(Debug, Clone)]
-struct DiskMap {
+pub struct DiskMap {
     layout: Vec<File>,
 }
 impl DiskMap {
@@ -61,6 +63,7 @@ impl DiskMap {
         Self { layout }
     }
 
+    #[allow(dead_code)]
     fn print(&self) {
         for file in &self.layout {
             for _ in 0..file.len {
@@ -70,6 +73,7 @@ impl DiskMap {
         println!();
     }
 
+    #[allow(dead_code)]
     fn get_files(&self) -> Vec<(usize, &File)> {
         self.layout
             .iter()
@@ -79,6 +83,7 @@ impl DiskMap {
             .collect()
     }
 
+    #[allow(dead_code)]
     fn get_empty_spaces(&self) -> Vec<(usize, u8)> {
         self.layout
             .iter()
@@ -116,7 +121,7 @@ impl DiskMap {
             return None;
         }
 
-        return None;
+        None
     }
     fn pop_file(&mut self) -> File {
         while let Some(file) = self.layout.pop() {
@@ -150,8 +155,8 @@ impl DiskMap {
     }
 }
 
-fn part_1(input: &str) -> u64 {
-    let mut disk_map = DiskMap::from_input(input);
+fn part_1(disk_map: &DiskMap) -> u64 {
+    let mut disk_map = disk_map.clone();
 
     let mut i = 0;
     loop {
@@ -178,13 +183,13 @@ fn part_1(input: &str) -> u64 {
     disk_map.hash()
 }
 
-fn part_2(input: &str) -> u64 {
-    let mut disk_map = DiskMap::from_input(input);
+fn part_2(disk_map: &DiskMap) -> u64 {
+    let mut disk_map = disk_map.clone();
 
     let mut curr_id: Option<usize> = None;
 
     for i in (1..disk_map.layout.len()).rev() {
-        let move_file = disk_map.layout[i].clone();
+        let move_file = disk_map.layout[i];
         let move_id = match move_file.content {
             Content::Empty => continue,
             Content::File(id) => id,
@@ -222,16 +227,25 @@ fn part_2(input: &str) -> u64 {
     disk_map.hash()
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+pub struct Day9;
+pub const DAY: Day9 = Day9;
+impl Day for Day9 {
+    type Parsed = DiskMap;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    let start: Instant = Instant::now();
-    let part_1_answer = part_1(&input);
-    let duration = start.elapsed();
-    println!("Part 1 answer: {}, time: {:?}", part_1_answer, duration);
+    fn parse(&self, input: &str) -> Result<DiskMap, AocError> {
+        Ok(DiskMap::from_input(input))
+    }
+    fn try_part_1(&self, disk_map: &DiskMap) -> Result<u64, AocError> {
+        Ok(part_1(disk_map))
+    }
+    fn try_part_2(&mut self, disk_map: &DiskMap) -> Result<u64, AocError> {
+        Ok(part_2(disk_map))
+    }
+}
 
-    let start: Instant = Instant::now();
-    let part_2_answer = part_2(&input);
-    let duration = start.elapsed();
-    println!("Part 2 answer: {}, time: {:?}", part_2_answer, duration);
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
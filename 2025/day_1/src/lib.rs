@@ -1,4 +1,4 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 
 const DIAL_SIZE: u8 = 100;
 #[derive(Debug)]
@@ -10,7 +10,7 @@ impl Dial {
         Dial { position }
     }
 
-    fn rotate(&mut self, rotation: Rotate) -> Click {
+    fn rotate(&mut self, rotation: &Rotate) -> Click {
         // count whole rotations of dial
         let mut clicks: u8 = (rotation.to_amount().abs() / DIAL_SIZE as i32) as u8;
 
@@ -38,7 +38,7 @@ impl Dial {
 }
 
 #[derive(Debug)]
-enum Rotate {
+pub enum Rotate {
     Left(u32),
     Right(u32),
 }
@@ -66,38 +66,43 @@ struct Click {
     part_2: u8,
 }
 
-struct Day1;
-const DAY: Day1 = Day1;
-impl Day<u32> for Day1 {
-    fn part_1(&self, input: &str) -> u32 {
+pub struct Day1;
+pub const DAY: Day1 = Day1;
+impl Day for Day1 {
+    type Parsed = Vec<Rotate>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Rotate>, AocError> {
+        Ok(input.lines().map(Rotate::parse_line).collect())
+    }
+    fn try_part_1(&self, rotations: &Vec<Rotate>) -> Result<u32, AocError> {
         let mut part_1_answer = 0;
 
         let mut dial = Dial::new(50);
 
-        for line in input.lines() {
-            let rotate = Rotate::parse_line(line);
-
+        for rotate in rotations {
             if dial.rotate(rotate).part_1 {
                 part_1_answer += 1;
             }
         }
 
-        part_1_answer
+        Ok(part_1_answer)
     }
-    fn part_2(&self, input: &str) -> u32 {
+    fn try_part_2(&mut self, rotations: &Vec<Rotate>) -> Result<u32, AocError> {
         let mut part_2_answer = 0;
 
         let mut dial = Dial::new(50);
 
-        for line in input.lines() {
-            let rotate = Rotate::parse_line(line);
+        for rotate in rotations {
             part_2_answer += dial.rotate(rotate).part_2 as u32;
         }
 
-        part_2_answer
+        Ok(part_2_answer)
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
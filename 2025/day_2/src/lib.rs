@@ -1,9 +1,9 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 
 type Id = u64;
 
 #[derive(Debug)]
-struct GiftShop {
+pub struct GiftShop {
     product_id_ranges: Vec<ProductIdRange>,
 }
 impl GiftShop {
@@ -65,29 +65,33 @@ fn part_2_valid_id(number: Id) -> bool {
     true
 }
 
-struct Day2;
-const DAY: Day2 = Day2;
-impl Day<Id> for Day2 {
-    fn part_1(&self, input: &str) -> Id {
-        let gift_shop = GiftShop::parse(input);
+pub struct Day2;
+pub const DAY: Day2 = Day2;
+impl Day for Day2 {
+    type Parsed = GiftShop;
+    type Part1 = Id;
+    type Part2 = Id;
 
-        gift_shop
+    fn parse(&self, input: &str) -> Result<GiftShop, AocError> {
+        Ok(GiftShop::parse(input))
+    }
+    fn try_part_1(&self, gift_shop: &GiftShop) -> Result<Id, AocError> {
+        Ok(gift_shop
             .product_id_ranges
             .iter()
             .flat_map(|product_id_ranges| product_id_ranges.get_invalid_ids(&part_1_valid_id))
-            .sum::<Id>()
+            .sum::<Id>())
     }
-    fn part_2(&self, input: &str) -> Id {
-        let gift_shop = GiftShop::parse(input);
-
-        gift_shop
+    fn try_part_2(&mut self, gift_shop: &GiftShop) -> Result<Id, AocError> {
+        Ok(gift_shop
             .product_id_ranges
             .iter()
             .flat_map(|product_id_ranges| product_id_ranges.get_invalid_ids(&part_2_valid_id))
-            .sum::<Id>()
+            .sum::<Id>())
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
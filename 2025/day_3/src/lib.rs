@@ -1,8 +1,8 @@
-use library::input::{Day, InputType};
+use library::input::{AocError, Day};
 use std::collections::VecDeque;
 
 #[derive(Debug)]
-struct BatteryBank {
+pub struct BatteryBank {
     banks: Vec<Batteries>,
 }
 impl BatteryBank {
@@ -59,29 +59,33 @@ fn largest_numbers_in_order(slice: &[u8], size: usize) -> VecDeque<u8> {
     result
 }
 
-struct Day3;
-const DAY: Day3 = Day3;
-impl Day<u64> for Day3 {
-    fn part_1(&self, input: &str) -> u64 {
-        let battery_bank = BatteryBank::parse(input);
+pub struct Day3;
+pub const DAY: Day3 = Day3;
+impl Day for Day3 {
+    type Parsed = BatteryBank;
+    type Part1 = u64;
+    type Part2 = u64;
 
-        battery_bank
+    fn parse(&self, input: &str) -> Result<BatteryBank, AocError> {
+        Ok(BatteryBank::parse(input))
+    }
+    fn try_part_1(&self, battery_bank: &BatteryBank) -> Result<u64, AocError> {
+        Ok(battery_bank
             .banks
             .iter()
             .map(|bank| bank.largest_voltage(2))
-            .sum()
+            .sum())
     }
-    fn part_2(&self, input: &str) -> u64 {
-        let battery_bank = BatteryBank::parse(input);
-
-        battery_bank
+    fn try_part_2(&mut self, battery_bank: &BatteryBank) -> Result<u64, AocError> {
+        Ok(battery_bank
             .banks
             .iter()
             .map(|bank| bank.largest_voltage(12))
-            .sum()
+            .sum())
     }
 }
 
-fn main() -> std::io::Result<()> {
-    DAY.run(InputType::UserInput)
+#[cfg(test)]
+mod tests {
+    library::input::examples!(crate::DAY);
 }
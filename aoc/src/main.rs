@@ -0,0 +1,507 @@
+mod registry;
+mod report;
+mod scaffold;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use library::input::bench::{self, Baseline, BenchOptions, Measurement};
+use library::input::cache::AnswerCache;
+use library::input::store::{self, InputKey};
+use library::input::{AocError, InputType, Params, Part, Solution};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code solutions from any year and day")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the selected days and print their answers
+    Run(RunArgs),
+    /// Check the selected days against the answers recorded in their answers.toml
+    Verify(Selection),
+    /// Run every registered day and print a table of the answers, their status and timings
+    Report(ReportArgs),
+    /// Create the crate of a new day from the template and register it with the runner
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Encrypt or decrypt the input.txt of every registered day with the local key
+    Inputs {
+        #[arg(value_enum)]
+        action: InputsAction,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum InputsAction {
+    /// Make a new key, kept in the file named by AOC_KEY_FILE or in the config directory
+    Key,
+    /// Write input.txt.enc beside every input.txt that changed
+    Encrypt,
+    /// Write input.txt from every input.txt.enc
+    Decrypt,
+}
+
+impl InputsAction {
+    fn name(self) -> &'static str {
+        match self {
+            InputsAction::Key => "make a key for",
+            InputsAction::Encrypt => "encrypt",
+            InputsAction::Decrypt => "decrypt",
+        }
+    }
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Puzzle year, defaults to the latest registered year
+    year: Option<u16>,
+    /// Puzzle day, defaults to the latest registered day of the year
+    day: Option<u8>,
+    /// Select every registered day of the year, or of every year if none is given
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Only run one part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Use example.txt instead of input.txt
+    #[arg(long, group = "source")]
+    example: bool,
+    /// Use another example file in the day's directory, like example2.txt
+    #[arg(long, value_name = "FILE", group = "source")]
+    example_file: Option<String>,
+    /// Use this file instead of input.txt, or stdin if it is -
+    #[arg(long, value_name = "PATH", group = "source")]
+    input: Option<PathBuf>,
+    /// Use every .txt file in this directory of each day, like inputs, labelling the results with the file names
+    #[arg(long, value_name = "DIR", group = "source")]
+    inputs: Option<PathBuf>,
+    /// Override one of the day's constants, the value is written as TOML: --param part_1_steps=6
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    params: Vec<String>,
+}
+
+impl Selection {
+    fn entries(&self) -> Result<Vec<&'static registry::Entry>, &'static str> {
+        let entries = registry::select(self.year, self.day, self.all);
+        if entries.is_empty() {
+            return Err("No registered day matches the selection");
+        }
+        if entries.len() > 1 && self.input_type() == InputType::Stdin {
+            return Err("Only one day can read its input from stdin");
+        }
+        Ok(entries)
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::One],
+            Some(2) => vec![Part::Two],
+            _ => Part::ALL.to_vec(),
+        }
+    }
+
+    fn params(&self) -> Result<Params, AocError> {
+        Params::from_assignments(&self.params)
+    }
+
+    fn input_type(&self) -> InputType {
+        if let Some(path) = &self.input {
+            if path.as_os_str() == "-" {
+                InputType::Stdin
+            } else {
+                // days read their inputs relative to their own directory
+                InputType::File(std::path::absolute(path).unwrap_or_else(|_| path.clone()))
+            }
+        } else if let Some(dir) = &self.inputs {
+            InputType::Directory(dir.clone())
+        } else if let Some(name) = &self.example_file {
+            InputType::NamedExample(name.clone())
+        } else if self.example {
+            InputType::Example
+        } else {
+            InputType::UserInput
+        }
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// Solve every part again instead of printing the answers cached for the same input
+    #[arg(long)]
+    no_cache: bool,
+    /// Time parsing and each part over n runs instead of printing the answers
+    #[arg(short, long)]
+    n: Option<usize>,
+    /// Untimed runs of each step before timing starts [default: 0]
+    #[arg(long, requires = "n")]
+    warm_up: Option<usize>,
+    /// How to print the timings [default: text]
+    #[arg(long, requires = "n", value_enum)]
+    format: Option<Format>,
+    /// Compare the timings with a baseline saved by --save-baseline
+    #[arg(long, requires = "n")]
+    baseline: Option<PathBuf>,
+    /// Save the timings as a baseline for later runs to compare against
+    #[arg(long, requires = "n")]
+    save_baseline: Option<PathBuf>,
+    /// Percentage a median may grow over the baseline before it counts as a regression [default: 5]
+    #[arg(long, requires = "baseline")]
+    threshold: Option<f64>,
+}
+
+#[derive(Args)]
+struct ReportArgs {
+    /// Only run the days of this year
+    year: Option<u16>,
+    /// Use example.txt instead of input.txt
+    #[arg(long)]
+    example: bool,
+    /// Override one of the days' constants, the value is written as TOML: --param part_1_steps=6
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    params: Vec<String>,
+    /// Number of days run at the same time
+    #[arg(short, long, default_value_t = 1)]
+    jobs: usize,
+    /// Seconds a day may run before it is reported as timed out, 0 waits forever
+    #[arg(long, default_value_t = 60)]
+    timeout: u64,
+    /// Print the tables as Markdown
+    #[arg(long)]
+    markdown: bool,
+    /// Rewrite the progress checklist between the progress markers of this README
+    #[arg(long, value_name = "PATH", conflicts_with = "year")]
+    readme: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// Creates the entry's solution set up for the selected input and parameters.
+fn solution(
+    entry: &registry::Entry,
+    selection: &Selection,
+    params: &Params,
+) -> Result<Box<dyn Solution>, AocError> {
+    let mut solution = (entry.solution)();
+    solution.setup(&selection.input_type(), params)?;
+    Ok(solution)
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let selection = &args.selection;
+    let entries = match selection.entries() {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let params = match selection.params() {
+        Ok(params) => params,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(n) = args.n {
+        return bench(&args, entries, &params, n);
+    }
+
+    let parts = selection.parts();
+    let mut failed = false;
+
+    for entry in entries {
+        println!("{} day {}", entry.year, entry.day);
+
+        let mut solution = match solution(entry, selection, &params) {
+            Ok(solution) => solution,
+            Err(e) => {
+                eprintln!("Could not set up {} day {}: {e}", entry.year, entry.day);
+                failed = true;
+                continue;
+            }
+        };
+        let result = if args.no_cache {
+            solution.run_in(&entry.dir(), selection.input_type(), &parts)
+        } else {
+            run_cached(solution.as_mut(), entry, selection, &params)
+        };
+        if let Err(e) = result {
+            eprintln!("Could not read input in {}: {e}", entry.dir().display());
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_cached(
+    solution: &mut dyn Solution,
+    entry: &registry::Entry,
+    selection: &Selection,
+    params: &Params,
+) -> std::io::Result<()> {
+    let mut cache = AnswerCache::load(&entry.dir())?.with_params(params);
+    solution.run_cached_in(
+        &entry.dir(),
+        selection.input_type(),
+        &selection.parts(),
+        &mut cache,
+    )?;
+    cache.save()
+}
+
+fn bench(
+    args: &RunArgs,
+    entries: Vec<&'static registry::Entry>,
+    params: &Params,
+    n: usize,
+) -> ExitCode {
+    let baseline = match &args.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("Could not load baseline {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let selection = &args.selection;
+    let parts = selection.parts();
+    let options = BenchOptions::new(n).with_warm_up(args.warm_up.unwrap_or(0));
+    let format = args.format.unwrap_or(Format::Text);
+    let threshold = args.threshold.unwrap_or(5.0) / 100.0;
+    let mut measurements: Vec<Measurement> = Vec::new();
+    let mut failed = false;
+
+    for entry in entries {
+        if format == Format::Text {
+            println!("{} day {}", entry.year, entry.day);
+        }
+
+        let mut solution = match solution(entry, selection, params) {
+            Ok(solution) => solution,
+            Err(e) => {
+                eprintln!("Could not set up {} day {}: {e}", entry.year, entry.day);
+                failed = true;
+                continue;
+            }
+        };
+        let day_measurements =
+            match solution.bench_in(&entry.dir(), selection.input_type(), &parts, options) {
+                Ok(day_measurements) => day_measurements,
+                Err(e) => {
+                    eprintln!("Could not read input in {}: {e}", entry.dir().display());
+                    failed = true;
+                    continue;
+                }
+            };
+
+        for mut measurement in day_measurements {
+            let step = measurement.name;
+            measurement.name = format!("{}/day_{}/{step}", entry.year, entry.day);
+
+            let change = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.compare(&measurement, threshold));
+            failed |= change.is_some_and(|change| change.regression);
+
+            match (format, change) {
+                (Format::Text, Some(change)) => {
+                    println!("{step} {}\n    {change}", measurement.stats)
+                }
+                (Format::Text, None) => println!("{step} {}", measurement.stats),
+                // regressions still need to be seen when stdout is machine readable
+                (_, Some(change)) if change.regression => {
+                    eprintln!("{} {change}", measurement.name)
+                }
+                _ => (),
+            }
+            measurements.push(measurement);
+        }
+    }
+
+    match format {
+        Format::Text => (),
+        Format::Json => println!("{}", bench::to_json(&measurements)),
+        Format::Csv => print!("{}", bench::to_csv(&measurements)),
+    }
+
+    if let Some(path) = &args.save_baseline
+        && let Err(e) = Baseline::save(path, &measurements)
+    {
+        eprintln!("Could not save baseline {}: {e}", path.display());
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(selection: Selection) -> ExitCode {
+    let entries = match selection.entries() {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let params = match selection.params() {
+        Ok(params) => params,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = selection.parts();
+    let mut failed = false;
+
+    for entry in entries {
+        println!("{} day {}", entry.year, entry.day);
+
+        let mut solution = match solution(entry, &selection, &params) {
+            Ok(solution) => solution,
+            Err(e) => {
+                eprintln!("Could not set up {} day {}: {e}", entry.year, entry.day);
+                failed = true;
+                continue;
+            }
+        };
+        match solution.verify_in(&entry.dir(), selection.input_type(), &parts) {
+            Ok(passed) => failed |= !passed,
+            Err(e) => {
+                eprintln!("Could not verify {}: {e}", entry.dir().display());
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn report(args: ReportArgs) -> ExitCode {
+    let entries = registry::select(args.year, None, true);
+    if entries.is_empty() {
+        eprintln!("No registered day matches the selection");
+        return ExitCode::FAILURE;
+    }
+    let params = match Params::from_assignments(&args.params) {
+        Ok(params) => params,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let input_type = if args.example {
+        InputType::Example
+    } else {
+        InputType::UserInput
+    };
+    let timeout = (args.timeout > 0).then(|| Duration::from_secs(args.timeout));
+
+    let results = report::run_all(&entries, &input_type, &params, args.jobs, timeout);
+    print!("{}", report::table(&results, args.markdown));
+
+    if let Some(path) = &args.readme
+        && let Err(e) = report::update_readme(path, &results)
+    {
+        eprintln!("Could not update {}: {e}", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    if results.iter().all(|result| result.outcome.passed()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn new(year: u16, day: u8) -> ExitCode {
+    let root = registry::root();
+    match scaffold::new_day(&root, year, day) {
+        Ok(dir) => {
+            println!("Created {}", dir.display());
+            println!("Run it with: cargo run -p aoc -- run {year} {day}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Could not create {year} day {day}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn inputs(action: InputsAction) -> ExitCode {
+    let dirs: Vec<PathBuf> = registry::select(None, None, true)
+        .iter()
+        .map(|entry| entry.dir())
+        .collect();
+    let written = match action {
+        InputsAction::Key => match InputKey::generate() {
+            Ok((_, path)) => {
+                println!(
+                    "Saved a new key in {}, keep a copy of it somewhere safe",
+                    path.display()
+                );
+                return ExitCode::SUCCESS;
+            }
+            Err(e) => Err(e),
+        },
+        InputsAction::Encrypt => InputKey::load().and_then(|key| store::encrypt_all(&key, &dirs)),
+        InputsAction::Decrypt => InputKey::load().and_then(|key| store::decrypt_all(&key, &dirs)),
+    };
+
+    match written {
+        Ok(written) => {
+            for path in &written {
+                println!("Wrote {}", path.display());
+            }
+            println!("{} files written", written.len());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Could not {} the inputs: {e}", action.name());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Verify(selection) => verify(selection),
+        Command::Report(args) => report(args),
+        Command::New { year, day } => new(year, day),
+        Command::Inputs { action } => inputs(action),
+    }
+}
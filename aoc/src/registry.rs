@@ -0,0 +1,136 @@
+use library::input::{Day, Solution};
+use std::path::{Path, PathBuf};
+
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub solution: fn() -> Box<dyn Solution>,
+}
+impl Entry {
+    /// Directory of the day crate, which holds its `input.txt` and example files.
+    pub fn dir(&self) -> PathBuf {
+        root()
+            .join(self.year.to_string())
+            .join(format!("day_{}", self.day))
+    }
+}
+
+/// The repository, which holds the workspace and a directory for each year.
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate is inside the repository")
+        .to_path_buf()
+}
+
+macro_rules! register {
+    ($($year:literal $day:literal => $krate:ident,)*) => {
+        pub const DAYS: &[Entry] = &[
+            $(Entry {
+                year: $year,
+                day: $day,
+                solution: || $krate::DAY.into_solution(),
+            },)*
+        ];
+    };
+}
+
+register! {
+    2023 1 => aoc_2023_day_1,
+    2023 2 => aoc_2023_day_2,
+    2023 3 => aoc_2023_day_3,
+    2023 4 => aoc_2023_day_4,
+    2023 5 => aoc_2023_day_5,
+    2023 6 => aoc_2023_day_6,
+    2023 7 => aoc_2023_day_7,
+    2023 8 => aoc_2023_day_8,
+    2023 9 => aoc_2023_day_9,
+    2023 10 => aoc_2023_day_10,
+    2023 11 => aoc_2023_day_11,
+    2023 12 => aoc_2023_day_12,
+    2023 13 => aoc_2023_day_13,
+    2023 14 => aoc_2023_day_14,
+    2023 15 => aoc_2023_day_15,
+    2023 16 => aoc_2023_day_16,
+    2023 17 => aoc_2023_day_17,
+    2023 18 => aoc_2023_day_18,
+    2023 19 => aoc_2023_day_19,
+    2023 20 => aoc_2023_day_20,
+    2023 21 => aoc_2023_day_21,
+    2023 22 => aoc_2023_day_22,
+    2023 23 => aoc_2023_day_23,
+    2023 24 => aoc_2023_day_24,
+    2023 25 => aoc_2023_day_25,
+    2024 1 => aoc_2024_day_1,
+    2024 2 => aoc_2024_day_2,
+    2024 3 => aoc_2024_day_3,
+    2024 4 => aoc_2024_day_4,
+    2024 5 => aoc_2024_day_5,
+    2024 6 => aoc_2024_day_6,
+    2024 7 => aoc_2024_day_7,
+    2024 8 => aoc_2024_day_8,
+    2024 9 => aoc_2024_day_9,
+    2024 10 => aoc_2024_day_10,
+    2024 11 => aoc_2024_day_11,
+    2024 12 => aoc_2024_day_12,
+    2024 13 => aoc_2024_day_13,
+    2024 14 => aoc_2024_day_14,
+    2025 1 => aoc_2025_day_1,
+    2025 2 => aoc_2025_day_2,
+    2025 3 => aoc_2025_day_3,
+}
+
+/// Picks the registered days matching the selection, defaulting to the latest year and day.
+pub fn select(year: Option<u16>, day: Option<u8>, all: bool) -> Vec<&'static Entry> {
+    let year = match year {
+        Some(year) => Some(year),
+        None if all => None,
+        None => DAYS.iter().map(|entry| entry.year).max(),
+    };
+
+    let in_year: Vec<&Entry> = DAYS
+        .iter()
+        .filter(|entry| year.is_none_or(|year| entry.year == year))
+        .collect();
+
+    match day {
+        Some(day) => in_year
+            .into_iter()
+            .filter(|entry| entry.day == day)
+            .collect(),
+        None if all => in_year,
+        None => in_year
+            .into_iter()
+            .max_by_key(|entry| entry.day)
+            .into_iter()
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::{DAYS, select};
+
+    #[test]
+    fn test_select_defaults_to_latest_day() {
+        let latest_year = DAYS.iter().map(|entry| entry.year).max().unwrap();
+        let selected = select(None, None, false);
+
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].year, latest_year);
+    }
+    #[test]
+    fn test_select_all_days_of_year() {
+        let selected = select(Some(2023), None, true);
+
+        assert_eq!(selected.len(), 25);
+        assert!(selected.iter().all(|entry| entry.year == 2023));
+    }
+    #[test]
+    fn test_select_single_day() {
+        let selected = select(Some(2023), Some(17), false);
+
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].day, 17);
+    }
+}
@@ -0,0 +1,408 @@
+use crate::registry::{self, Entry};
+use library::input::answers::Verdict;
+use library::input::report::Report;
+use library::input::{InputType, Params, Part, days_in};
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+use std::{fs, io};
+
+const PROGRESS_START: &str = "<!-- progress -->";
+const PROGRESS_END: &str = "<!-- /progress -->";
+
+pub enum Outcome {
+    Done(Report),
+    /// The input could not be read or the parameters did not fit the day.
+    Failed(String),
+    Panicked(String),
+    TimedOut,
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        matches!(self, Outcome::Done(report) if report.passed())
+    }
+
+    /// Every part gave its recorded answer.
+    pub fn verified(&self) -> bool {
+        matches!(self, Outcome::Done(report) if report.verified())
+    }
+}
+
+pub struct DayResult {
+    pub entry: &'static Entry,
+    pub outcome: Outcome,
+}
+
+/// Runs every entry, `jobs` at a time, each on its own thread so a panic only fails that day.
+/// A day still running after `timeout` is reported as timed out and left to finish in the background.
+pub fn run_all(
+    entries: &[&'static Entry],
+    input_type: &InputType,
+    params: &Params,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<DayResult> {
+    // the panic messages end up in the table instead
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let (sender, receiver) = mpsc::channel();
+    let mut outcomes: Vec<Option<Outcome>> = entries.iter().map(|_| None).collect();
+    let mut running: Vec<(usize, Instant)> = Vec::new();
+    let mut next = 0;
+
+    while next < entries.len() || !running.is_empty() {
+        while running.len() < jobs.max(1) && next < entries.len() {
+            let entry = entries[next];
+            let input_type = input_type.clone();
+            let params = params.clone();
+            let sender = sender.clone();
+            let index = next;
+
+            thread::spawn(move || {
+                let outcome =
+                    panic::catch_unwind(AssertUnwindSafe(|| run_day(entry, input_type, &params)))
+                        .unwrap_or_else(|payload| Outcome::Panicked(panic_message(payload)));
+                // the receiver is gone once the day has timed out
+                let _ = sender.send((index, outcome));
+            });
+            running.push((index, Instant::now()));
+            next += 1;
+        }
+
+        let received = match timeout {
+            Some(timeout) => {
+                let wait = running
+                    .iter()
+                    .map(|(_, start)| timeout.saturating_sub(start.elapsed()))
+                    .min()
+                    .unwrap_or_default();
+                receiver.recv_timeout(wait).ok()
+            }
+            None => receiver.recv().ok(),
+        };
+        if let Some((index, outcome)) = received {
+            running.retain(|(running, _)| *running != index);
+            outcomes[index] = Some(outcome);
+        }
+        if let Some(timeout) = timeout {
+            running.retain(|&(index, start)| {
+                let timed_out = start.elapsed() >= timeout;
+                if timed_out {
+                    outcomes[index] = Some(Outcome::TimedOut);
+                }
+                !timed_out
+            });
+        }
+    }
+
+    panic::set_hook(previous_hook);
+
+    entries
+        .iter()
+        .zip(outcomes)
+        .map(|(&entry, outcome)| DayResult {
+            entry,
+            outcome: outcome.expect("every day finished or timed out"),
+        })
+        .collect()
+}
+
+fn run_day(entry: &Entry, input_type: InputType, params: &Params) -> Outcome {
+    let mut solution = (entry.solution)();
+    if let Err(e) = solution.setup(&input_type, params) {
+        return Outcome::Failed(e.to_string());
+    }
+    match solution.report_in(&entry.dir(), input_type, &Part::ALL) {
+        Ok(report) => Outcome::Done(report),
+        Err(e) => Outcome::Failed(format!("could not read input: {e}")),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/// Rows of year, day, part, answer, status and time, followed by the totals of each year.
+pub fn table(results: &[DayResult], markdown: bool) -> String {
+    let mut rows = Vec::new();
+    for result in results {
+        let (year, day) = (result.entry.year.to_string(), result.entry.day.to_string());
+        let mut row = |part: &str, answer: &str, status: String, time: Option<Duration>| {
+            rows.push(vec![
+                year.clone(),
+                day.clone(),
+                part.to_string(),
+                answer.replace('\n', " "),
+                status,
+                time.map_or(String::new(), |time| format!("{time:?}")),
+            ])
+        };
+
+        match &result.outcome {
+            Outcome::Done(report) => {
+                match report.parse_time {
+                    Some(time) => row("parse", "", String::new(), Some(time)),
+                    None => row("parse", "", "ERROR".to_string(), None),
+                }
+                for part in &report.parts {
+                    let (answer, status) = match &part.verdict {
+                        Verdict::Pass(actual) => (actual.as_str(), "pass".to_string()),
+                        Verdict::Missing(actual) => (actual.as_str(), "missing".to_string()),
+                        Verdict::Fail { expected, actual } => {
+                            (actual.as_str(), format!("FAIL, expected {expected}"))
+                        }
+                        Verdict::Error(e) => ("", format!("ERROR: {e}")),
+                    };
+                    row(
+                        &part.part.number().to_string(),
+                        answer,
+                        status,
+                        Some(part.time),
+                    );
+                }
+            }
+            Outcome::Failed(e) => row("", "", format!("ERROR: {e}"), None),
+            Outcome::Panicked(message) => row("", "", format!("PANIC: {message}"), None),
+            Outcome::TimedOut => row("", "", "TIMEOUT".to_string(), None),
+        }
+    }
+
+    let mut totals = Vec::new();
+    let years: BTreeSet<u16> = results.iter().map(|result| result.entry.year).collect();
+    for year in years {
+        let in_year: Vec<&DayResult> = results
+            .iter()
+            .filter(|result| result.entry.year == year)
+            .collect();
+        let time: Duration = in_year
+            .iter()
+            .filter_map(|result| match &result.outcome {
+                Outcome::Done(report) => Some(report.total_time()),
+                _ => None,
+            })
+            .sum();
+        let passed = in_year
+            .iter()
+            .filter(|result| result.outcome.verified())
+            .count();
+        let failed = in_year
+            .iter()
+            .filter(|result| !result.outcome.passed())
+            .count();
+
+        totals.push(vec![
+            year.to_string(),
+            in_year.len().to_string(),
+            passed.to_string(),
+            failed.to_string(),
+            (in_year.len() - passed - failed).to_string(),
+            format!("{time:?}"),
+        ]);
+    }
+
+    let mut text = render(
+        &["Year", "Day", "Part", "Answer", "Status", "Time"],
+        &rows,
+        markdown,
+    );
+    text.push('\n');
+    text.push_str(&render(
+        &["Year", "Days", "Passed", "Failed", "Missing", "Time"],
+        &totals,
+        markdown,
+    ));
+    text
+}
+
+fn render(header: &[&str], rows: &[Vec<String>], markdown: bool) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|cell| cell.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header: Vec<String> = header.iter().map(|cell| cell.to_string()).collect();
+    let line = |cells: &[String]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:width$}"))
+            .collect();
+        if markdown {
+            format!("| {} |\n", cells.join(" | "))
+        } else {
+            format!("{}\n", cells.join("  ").trim_end())
+        }
+    };
+
+    let mut text = line(&header);
+    if markdown {
+        let dashes: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+        text.push_str(&line(&dashes));
+    }
+    for row in rows {
+        text.push_str(&line(row));
+    }
+    text
+}
+
+/// The README's checklist, a day is ticked when every part gave its recorded answer and unticked
+/// when a part gave a wrong answer or an error, or the day panicked or timed out. Days without
+/// recorded answers, and days that weren't run or couldn't be, keep their tick from `previous`.
+pub fn progress(results: &[DayResult], previous: &str) -> String {
+    let ticked = ticked_days(previous);
+    let years: BTreeSet<u16> = registry::DAYS.iter().map(|entry| entry.year).collect();
+
+    let mut text = String::new();
+    for year in years {
+        writeln!(text, "## {year}\n").unwrap();
+        for day in 1..=days_in(year) {
+            let result = results
+                .iter()
+                .find(|result| result.entry.year == year && result.entry.day == day);
+            let done = match result.map(|result| &result.outcome) {
+                Some(outcome) if outcome.verified() => true,
+                Some(outcome @ Outcome::Done(_)) if outcome.passed() => {
+                    ticked.contains(&(year, day))
+                }
+                None | Some(Outcome::Failed(_)) => ticked.contains(&(year, day)),
+                Some(_) => false,
+            };
+            writeln!(text, "- [{}] Day {day}", if done { "x" } else { " " }).unwrap();
+        }
+        text.push('\n');
+    }
+    text
+}
+
+// the year and day of every ticked line in a checklist written by `progress`
+fn ticked_days(checklist: &str) -> BTreeSet<(u16, u8)> {
+    let mut year = None;
+    let mut ticked = BTreeSet::new();
+    for line in checklist.lines() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            year = heading.trim().parse().ok();
+        } else if let Some(day) = line.strip_prefix("- [x] Day ")
+            && let (Some(year), Ok(day)) = (year, day.trim().parse())
+        {
+            ticked.insert((year, day));
+        }
+    }
+    ticked
+}
+
+/// Replaces the part of the README between the progress markers with the checklist of `results`.
+pub fn update_readme(path: &std::path::Path, results: &[DayResult]) -> io::Result<()> {
+    let readme = fs::read_to_string(path)?;
+    let (Some(start), Some(end)) = (readme.find(PROGRESS_START), readme.find(PROGRESS_END)) else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{PROGRESS_START} and {PROGRESS_END} markers not found"),
+        ));
+    };
+
+    let start = start + PROGRESS_START.len();
+    let progress = progress(results, &readme[start..end]);
+    let updated = format!("{}\n\n{progress}{}", &readme[..start], &readme[end..]);
+    fs::write(path, updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::DAYS;
+    use crate::report::{DayResult, Outcome, progress, render};
+    use library::input::Part;
+    use library::input::answers::Verdict;
+    use library::input::report::{PartReport, Report};
+    use std::time::Duration;
+
+    #[test]
+    fn test_render() {
+        let rows = vec![
+            vec!["2023".to_string(), "17".to_string(), "102".to_string()],
+            vec!["2024".to_string(), "1".to_string(), String::new()],
+        ];
+
+        assert_eq!(
+            render(&["Year", "Day", "Answer"], &rows, false),
+            "Year  Day  Answer\n2023  17   102\n2024  1\n"
+        );
+        assert_eq!(
+            render(&["Year", "Day", "Answer"], &rows, true),
+            "| Year | Day | Answer |\n| ---- | --- | ------ |\n| 2023 | 17  | 102    |\n| 2024 | 1   |        |\n"
+        );
+    }
+    #[test]
+    fn test_progress() {
+        let report = |verdict: Verdict| Report {
+            parse_time: Some(Duration::ZERO),
+            parts: Part::ALL
+                .into_iter()
+                .map(|part| PartReport {
+                    part,
+                    verdict: verdict.clone(),
+                    time: Duration::ZERO,
+                })
+                .collect(),
+        };
+        let result = |day: u8, outcome: Outcome| DayResult {
+            entry: DAYS
+                .iter()
+                .find(|entry| entry.year == 2023 && entry.day == day)
+                .unwrap(),
+            outcome,
+        };
+
+        let results = [
+            result(1, Outcome::Done(report(Verdict::Pass("1".to_string())))),
+            result(2, Outcome::Done(report(Verdict::Missing("2".to_string())))),
+            result(3, Outcome::Failed("input.txt not found".to_string())),
+            result(5, Outcome::Panicked("oops".to_string())),
+            result(6, Outcome::Done(report(Verdict::Missing("6".to_string())))),
+            result(
+                7,
+                Outcome::Done(report(Verdict::Fail {
+                    expected: "7".to_string(),
+                    actual: "8".to_string(),
+                })),
+            ),
+        ];
+        let previous =
+            "## 2023\n\n- [x] Day 2\n- [x] Day 3\n- [x] Day 4\n- [x] Day 5\n- [x] Day 7\n";
+        let checklist = progress(&results, previous);
+
+        let year: Vec<&str> = checklist
+            .split("## ")
+            .find(|year| year.starts_with("2023"))
+            .unwrap()
+            .lines()
+            .skip(2)
+            .take(7)
+            .collect();
+        assert_eq!(
+            year,
+            [
+                "- [x] Day 1",
+                "- [x] Day 2",
+                "- [x] Day 3",
+                "- [x] Day 4",
+                "- [ ] Day 5",
+                "- [ ] Day 6",
+                "- [ ] Day 7"
+            ]
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use library::input::days_in;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_RS: &str = include_str!("../templates/lib.rs.template");
+const EXAMPLES_TOML: &str = include_str!("../templates/examples.toml.template");
+
+/// Creates the crate of a new day in the repository at `root` from the templates and registers it in
+/// the workspace, the runner's dependencies and the registry. An input, puzzle or example files
+/// already downloaded into the day's directory are kept. Returns the day's directory.
+pub fn new_day(root: &Path, year: u16, day: u8) -> io::Result<PathBuf> {
+    if day == 0 || day > days_in(year) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{year} only has days 1 to {}", days_in(year)),
+        ));
+    }
+    let dir = root.join(year.to_string()).join(format!("day_{day}"));
+    if dir.join("Cargo.toml").exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already has a crate", dir.display()),
+        ));
+    }
+
+    // every registration is worked out before anything is written so a failure leaves no half-made day
+    let edits = [
+        (
+            root.join("Cargo.toml"),
+            workspace_member as Key,
+            format!("    \"{year}/day_{day}\","),
+        ),
+        (
+            root.join("aoc").join("Cargo.toml"),
+            dependency,
+            format!("aoc_{year}_day_{day} = {{ path = \"../{year}/day_{day}\" }}"),
+        ),
+        (
+            root.join("aoc").join("src").join("registry.rs"),
+            registration,
+            format!("    {year} {day} => aoc_{year}_day_{day},"),
+        ),
+    ];
+    let mut edited = Vec::new();
+    for (path, key, line) in edits {
+        let text = fs::read_to_string(&path)?;
+        let text = insert_sorted(&text, key, (year, day), &line).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+        })?;
+        edited.push((path, text));
+    }
+
+    let fill = |template: &str| {
+        template
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+    };
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), fill(CARGO_TOML))?;
+    fs::write(dir.join("src").join("lib.rs"), fill(LIB_RS))?;
+    if !dir.join("examples.toml").exists() {
+        fs::write(dir.join("examples.toml"), EXAMPLES_TOML)?;
+    }
+    for (path, text) in edited {
+        fs::write(path, text)?;
+    }
+    Ok(dir)
+}
+
+/// Reads the year and day a line lists, if it lists one.
+type Key = fn(&str) -> Option<(u16, u8)>;
+
+// `    "2025/day_3",` in the workspace members
+fn workspace_member(line: &str) -> Option<(u16, u8)> {
+    let member = line.trim().strip_prefix('"')?.strip_suffix("\",")?;
+    let (year, day) = member.split_once("/day_")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+// `aoc_2025_day_3 = { path = "../2025/day_3" }` in the runner's dependencies
+fn dependency(line: &str) -> Option<(u16, u8)> {
+    let (name, _) = line.strip_prefix("aoc_")?.split_once(" =")?;
+    let (year, day) = name.split_once("_day_")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+// `    2025 3 => aoc_2025_day_3,` in the registry
+fn registration(line: &str) -> Option<(u16, u8)> {
+    let (date, _) = line.trim().split_once(" => ")?;
+    let (year, day) = date.split_once(' ')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Adds `new_line` among the lines that list days so they stay in order of year and day.
+fn insert_sorted(text: &str, key: Key, new: (u16, u8), new_line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let listed: Vec<(usize, (u16, u8))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, key(line)?)))
+        .collect();
+
+    if listed.iter().any(|&(_, listed)| listed == new) {
+        return Err(format!("{} day {} is already listed", new.0, new.1));
+    }
+    let at = match listed.iter().rev().find(|&&(_, listed)| listed < new) {
+        Some((i, _)) => i + 1,
+        None => listed.first().ok_or("no days are listed to add to")?.0,
+    };
+    lines.insert(at, new_line);
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{dependency, insert_sorted, new_day, registration, workspace_member};
+    use std::fs;
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(workspace_member("    \"2023/day_17\","), Some((2023, 17)));
+        assert_eq!(workspace_member("    \"aoc\","), None);
+        assert_eq!(
+            dependency("aoc_2024_day_3 = { path = \"../2024/day_3\" }"),
+            Some((2024, 3))
+        );
+        assert_eq!(
+            registration("    2025 12 => aoc_2025_day_12,"),
+            Some((2025, 12))
+        );
+        assert_eq!(
+            registration("    ($($year:literal $day:literal => $krate:ident,)*) => {"),
+            None
+        );
+    }
+    #[test]
+    fn test_insert_sorted() {
+        let text = "members = [\n    \"aoc\",\n    \"2024/day_9\",\n    \"2024/day_10\",\n]\n";
+
+        assert_eq!(
+            insert_sorted(text, workspace_member, (2024, 2), "    \"2024/day_2\",").unwrap(),
+            "members = [\n    \"aoc\",\n    \"2024/day_2\",\n    \"2024/day_9\",\n    \"2024/day_10\",\n]\n"
+        );
+        assert_eq!(
+            insert_sorted(text, workspace_member, (2025, 1), "    \"2025/day_1\",").unwrap(),
+            "members = [\n    \"aoc\",\n    \"2024/day_9\",\n    \"2024/day_10\",\n    \"2025/day_1\",\n]\n"
+        );
+        assert!(insert_sorted(text, workspace_member, (2024, 9), "").is_err());
+    }
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::create_dir_all(root.join("2025/day_2")).unwrap();
+        fs::write(root.join("2025/day_2/input.txt"), "12\n").unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2025/day_1\",\n    \"2025/day_3\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\naoc_2025_day_1 = { path = \"../2025/day_1\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/registry.rs"),
+            "register! {\n    2025 1 => aoc_2025_day_1,\n}\n",
+        )
+        .unwrap();
+
+        let dir = new_day(&root, 2025, 2).unwrap();
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        let (members, dependencies, registry) = (
+            read("Cargo.toml"),
+            read("aoc/Cargo.toml"),
+            read("aoc/src/registry.rs"),
+        );
+        let (manifest, lib, input) = (
+            read("2025/day_2/Cargo.toml"),
+            read("2025/day_2/src/lib.rs"),
+            read("2025/day_2/input.txt"),
+        );
+        let again = new_day(&root, 2025, 2);
+        let out_of_range = new_day(&root, 2025, 13);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(dir, root.join("2025/day_2"));
+        assert_eq!(
+            members,
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2025/day_1\",\n    \"2025/day_2\",\n    \"2025/day_3\",\n]\n"
+        );
+        assert_eq!(
+            dependencies,
+            "[dependencies]\naoc_2025_day_1 = { path = \"../2025/day_1\" }\naoc_2025_day_2 = { path = \"../2025/day_2\" }\n"
+        );
+        assert_eq!(
+            registry,
+            "register! {\n    2025 1 => aoc_2025_day_1,\n    2025 2 => aoc_2025_day_2,\n}\n"
+        );
+        assert!(manifest.contains("name = \"aoc_2025_day_2\""));
+        assert!(lib.contains("pub const DAY: Day2 = Day2;"));
+        assert_eq!(input, "12\n");
+        assert!(again.is_err());
+        assert!(out_of_range.is_err());
+    }
+}
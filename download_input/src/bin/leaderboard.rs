@@ -0,0 +1,97 @@
+use clap::Parser;
+use download_input::client::{self, AocClient};
+use download_input::leaderboard::{self, Leaderboard};
+use download_input::session::Session;
+use reqwest::Url;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::SystemTime;
+use std::{fs, io};
+
+#[derive(Parser)]
+#[command(about = "Shows a private leaderboard, fetched at most every 15 minutes")]
+struct Cli {
+    year: u16,
+    /// The leaderboard's id, the number at the end of its URL
+    id: u64,
+    /// Also show when everyone solved this day, defaults to the last day with any stars
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Only use the cached leaderboard, however old
+    #[arg(long)]
+    offline: bool,
+    /// Directory of the cached leaderboards, defaults to aoc in the user's cache directory
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+    /// Site to fetch from, like a local stand-in server for testing
+    #[arg(long, default_value = client::DEFAULT_BASE_URL)]
+    base_url: Url,
+}
+
+fn cached_at(path: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|file| file.modified()).ok()
+}
+
+async fn fetch(cli: &Cli, cache: &PathBuf) -> Result<String, String> {
+    let session = Session::load().map_err(|e| e.to_string())?;
+    let client = AocClient::new(cli.base_url.clone(), session);
+    let json = client
+        .leaderboard(cli.year, cli.id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if let Some(dir) = cache.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    fs::write(cache, &json).map_err(|e| format!("can't cache the leaderboard: {e}"))?;
+    Ok(json)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let Some(dir) = cli
+        .cache_dir
+        .clone()
+        .or_else(|| dirs::cache_dir().map(|dir| dir.join("aoc")))
+    else {
+        eprintln!("No cache directory, pick one with --cache-dir");
+        return ExitCode::FAILURE;
+    };
+    let cache = dir.join(format!("leaderboard_{}_{}.json", cli.year, cli.id));
+
+    let fresh =
+        cached_at(&cache).is_some_and(|saved| leaderboard::is_fresh(saved, SystemTime::now()));
+    let json = if cli.offline || fresh {
+        fs::read_to_string(&cache).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => "the leaderboard hasn't been fetched yet".to_string(),
+            _ => format!("can't read {}: {e}", cache.display()),
+        })
+    } else {
+        fetch(&cli, &cache).await
+    };
+    let leaderboard =
+        match json.and_then(|json| Leaderboard::parse(&json).map_err(|e| e.to_string())) {
+            Ok(leaderboard) => leaderboard,
+            Err(e) => {
+                eprintln!("Can't load the leaderboard: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+    if let Some(saved) = cached_at(&cache)
+        && let Ok(age) = SystemTime::now().duration_since(saved)
+    {
+        println!("Fetched {} minutes ago\n", age.as_secs() / 60);
+    }
+    println!("{}", leaderboard.render_standings());
+    println!("Rank after each day\n{}", leaderboard.render_ranks());
+    // nobody has a star yet before the first day
+    let day = cli.day.unwrap_or(leaderboard.last_day());
+    if day > 0 {
+        print!("Day {day}\n{}", leaderboard.render_day(day));
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,80 @@
+use clap::Parser;
+use download_input::client::{self, AocClient};
+use download_input::day_dir;
+use download_input::session::Session;
+use download_input::submit::{AnswersFile, Outcome};
+use reqwest::Url;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Submits an answer and records the result in ./{year}/day_{day}/answers.toml")]
+struct Cli {
+    year: u16,
+    day: u8,
+    /// Part of the puzzle, 1 or 2
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    answer: String,
+    /// Site to submit to, like a local stand-in server for testing
+    #[arg(long, default_value = client::DEFAULT_BASE_URL)]
+    base_url: Url,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let answer = cli.answer.trim();
+
+    let mut answers = match AnswersFile::load(&day_dir(cli.year, cli.day)) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Can't read the answers: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(correct) = answers.correct(cli.part) {
+        if correct == answer {
+            println!("{answer} is already recorded as the answer");
+            return ExitCode::SUCCESS;
+        }
+        eprintln!("The answer is already recorded as {correct}, not submitting {answer}");
+        return ExitCode::FAILURE;
+    }
+    if answers.is_known_wrong(cli.part, answer) {
+        eprintln!("{answer} was already submitted and is wrong, not submitting it again");
+        return ExitCode::FAILURE;
+    }
+
+    let session = match Session::load() {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = AocClient::new(cli.base_url, session);
+
+    let outcome = match client.submit(cli.year, cli.day, cli.part, answer).await {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("{} day {} part {}: {e}", cli.year, cli.day, cli.part);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("{} day {} part {}: {outcome}", cli.year, cli.day, cli.part);
+
+    if outcome != Outcome::Correct && !outcome.is_wrong() {
+        return ExitCode::FAILURE;
+    }
+    answers.record(cli.part, answer, &outcome);
+    if let Err(e) = answers.save() {
+        eprintln!("Can't record the answer: {e}");
+        return ExitCode::FAILURE;
+    }
+
+    if outcome == Outcome::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,417 @@
+use crate::session::Session;
+use crate::submit::Outcome;
+use reqwest::{Client, RequestBuilder, StatusCode, Url, header, redirect};
+use scraper::{Html, Selector};
+use std::fmt;
+use std::time::Duration;
+
+const USER_AGENT: &str = "github.com/ThomasDavidson/Advent-of-Code by endershadow909@gmail.com";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// 400, the session cookie was not accepted.
+    BadSession,
+    /// The site shows the session as logged out, once it has expired.
+    LoggedOut,
+    /// 404, the puzzle is not released yet or does not exist.
+    NotFound,
+    /// 3xx, usually to the login page once the session has expired.
+    Redirect(Option<String>),
+    /// 5xx or 429, still failing after every retry.
+    Server(StatusCode),
+    Status(StatusCode),
+    /// The body was not the input or page asked for, like a login page, starting with this line.
+    NotPuzzleText(String),
+    Network(reqwest::Error),
+}
+
+impl FetchError {
+    /// Every later request would fail the same way, so there is no point going on.
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            FetchError::BadSession
+                | FetchError::LoggedOut
+                | FetchError::Redirect(_)
+                | FetchError::NotPuzzleText(_)
+        )
+    }
+
+    fn is_transient(&self) -> bool {
+        match self {
+            FetchError::Server(_) => true,
+            FetchError::Network(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::BadSession => {
+                write!(f, "bad request, the session cookie was not accepted")
+            }
+            FetchError::LoggedOut => write!(
+                f,
+                "not logged in, the session has expired, copy a new session cookie from the browser"
+            ),
+            FetchError::NotFound => write!(f, "not found, the puzzle may not be released yet"),
+            FetchError::Redirect(Some(location)) => {
+                write!(f, "redirected to {location}, the session may have expired")
+            }
+            FetchError::Redirect(None) => write!(f, "redirected, the session may have expired"),
+            FetchError::Server(status) => write!(f, "server error {status}"),
+            FetchError::Status(status) => write!(f, "unexpected status {status}"),
+            FetchError::NotPuzzleText(line) => {
+                write!(f, "unexpected response, is the session logged in? {line}")
+            }
+            FetchError::Network(e) => write!(f, "request failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Downloads from the Advent of Code site, or from a stand-in server at another base URL.
+pub struct AocClient {
+    client: Client,
+    base_url: Url,
+    session: Session,
+    retries: u32,
+    backoff: Duration,
+}
+
+impl AocClient {
+    pub fn new(base_url: Url, session: Session) -> Self {
+        let client = Client::builder()
+            .redirect(redirect::Policy::none())
+            .timeout(Duration::from_secs(30))
+            .build()
+            .expect("client settings are valid");
+
+        // joining replaces the last segment of a base without a trailing slash
+        let mut base_url = base_url;
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+
+        Self {
+            client,
+            base_url,
+            session,
+            retries: 3,
+            backoff: Duration::from_secs(1),
+        }
+    }
+
+    /// Transient failures are retried `retries` times, waiting `backoff` and doubling it every time.
+    pub fn with_retries(mut self, retries: u32, backoff: Duration) -> Self {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    fn url(&self, path: &str) -> Url {
+        self.base_url
+            .join(path)
+            .expect("puzzle paths are valid urls")
+    }
+
+    pub async fn input(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        self.get(&format!("{year}/day/{day}/input"), check_puzzle_text)
+            .await
+    }
+
+    /// The puzzle's page, with part 2's description once part 1 is solved.
+    pub async fn puzzle(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        self.get(&format!("{year}/day/{day}"), check_puzzle_page)
+            .await
+    }
+
+    async fn get(
+        &self,
+        path: &str,
+        check: fn(String) -> Result<String, FetchError>,
+    ) -> Result<String, FetchError> {
+        let url = self.url(path);
+
+        let mut attempt = 0;
+        loop {
+            let text = self.send(self.client.get(url.clone())).await;
+            match text.and_then(check) {
+                Err(e) if e.is_transient() && attempt < self.retries => {
+                    let delay = self.backoff * 2u32.pow(attempt);
+                    eprintln!("{e}, retrying in {delay:?}");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// The name of the logged in user, to check the session before downloading anything.
+    pub async fn whoami(&self) -> Result<String, FetchError> {
+        let page = self.get("", Ok).await?;
+        logged_in_user(&page).ok_or(FetchError::LoggedOut)
+    }
+
+    /// A private leaderboard's JSON, see [`Leaderboard`](crate::leaderboard::Leaderboard).
+    pub async fn leaderboard(&self, year: u16, id: u64) -> Result<String, FetchError> {
+        self.get(
+            &format!("{year}/leaderboard/private/view/{id}.json"),
+            check_json,
+        )
+        .await
+    }
+
+    /// Posts `answer` for part `level`. Submissions are not retried, a repeat could count as another
+    /// wrong answer.
+    pub async fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        level: u8,
+        answer: &str,
+    ) -> Result<Outcome, FetchError> {
+        let url = self.url(&format!("{year}/day/{day}/answer"));
+        let form = [("level", level.to_string()), ("answer", answer.to_string())];
+        let text = self.send(self.client.post(url).form(&form)).await?;
+        Ok(Outcome::parse(&text))
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<String, FetchError> {
+        let response = request
+            .header(header::COOKIE, self.session.cookie())
+            .header(header::USER_AGENT, USER_AGENT)
+            .send()
+            .await
+            .map_err(FetchError::Network)?;
+
+        let status = response.status();
+        if status == StatusCode::BAD_REQUEST {
+            return Err(FetchError::BadSession);
+        }
+        if status == StatusCode::NOT_FOUND {
+            return Err(FetchError::NotFound);
+        }
+        if status.is_redirection() {
+            let location = response
+                .headers()
+                .get(header::LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .map(str::to_string);
+            return Err(FetchError::Redirect(location));
+        }
+        if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(FetchError::Server(status));
+        }
+        if !status.is_success() {
+            return Err(FetchError::Status(status));
+        }
+
+        response.text().await.map_err(FetchError::Network)
+    }
+}
+
+// a logged out session gets a web page or a message asking to log in instead of the input
+fn check_puzzle_text(text: String) -> Result<String, FetchError> {
+    let first_line = text.trim_start().lines().next().unwrap_or_default();
+    if text.trim().is_empty() || first_line.starts_with('<') || text.contains("Please log in") {
+        let line: String = first_line.chars().take(80).collect();
+        return Err(FetchError::NotPuzzleText(line));
+    }
+    Ok(text)
+}
+
+// the header shows the user's name, or links to log in
+fn logged_in_user(page: &str) -> Option<String> {
+    let document = Html::parse_document(page);
+    let user = Selector::parse("header div.user").expect("selector is valid");
+    let name = document.select(&user).next()?.text().next()?.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+// a leaderboard the session can't see is answered with a page instead
+fn check_json(text: String) -> Result<String, FetchError> {
+    if !text.trim_start().starts_with('{') {
+        let line = text.trim_start().lines().next().unwrap_or_default();
+        return Err(FetchError::NotPuzzleText(line.chars().take(80).collect()));
+    }
+    Ok(text)
+}
+
+fn check_puzzle_page(text: String) -> Result<String, FetchError> {
+    if !text.contains("<article") {
+        let line = text.trim_start().lines().next().unwrap_or_default();
+        return Err(FetchError::NotPuzzleText(line.chars().take(80).collect()));
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::{AocClient, FetchError};
+    use crate::session::Session;
+    use crate::submit::Outcome;
+    use reqwest::{StatusCode, Url};
+    use std::time::Duration;
+
+    async fn client(server: &mockito::Server) -> AocClient {
+        AocClient::new(
+            Url::parse(&server.url()).unwrap(),
+            Session::parse("abc").unwrap(),
+        )
+        .with_retries(2, Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn test_input() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/2024/day/1/input")
+            .match_header("cookie", "session=abc")
+            .with_body("3   4\n4   3\n")
+            .create_async()
+            .await;
+
+        let input = client(&server).await.input(2024, 1).await.unwrap();
+
+        assert_eq!(input, "3   4\n4   3\n");
+        mock.assert_async().await;
+    }
+    #[tokio::test]
+    async fn test_statuses_are_not_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let not_found = server
+            .mock("GET", "/2025/day/12/input")
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+        let redirect = server
+            .mock("GET", "/2025/day/1/input")
+            .with_status(302)
+            .with_header("location", "/auth/login")
+            .create_async()
+            .await;
+        let client = client(&server).await;
+
+        assert!(matches!(
+            client.input(2025, 12).await,
+            Err(FetchError::NotFound)
+        ));
+        match client.input(2025, 1).await {
+            Err(e @ FetchError::Redirect(Some(_))) => assert!(e.is_fatal()),
+            result => panic!("expected a redirect, got {result:?}"),
+        }
+        not_found.assert_async().await;
+        redirect.assert_async().await;
+    }
+    #[tokio::test]
+    async fn test_server_errors_are_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/2024/day/2/input")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let result = client(&server).await.input(2024, 2).await;
+
+        assert!(matches!(
+            result,
+            Err(FetchError::Server(StatusCode::SERVICE_UNAVAILABLE))
+        ));
+        mock.assert_async().await;
+    }
+    #[tokio::test]
+    async fn test_login_page() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/2024/day/3/input")
+            .with_body("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/2024/day/4/input")
+            .with_body("<!DOCTYPE html>\n<html lang=\"en-us\">")
+            .create_async()
+            .await;
+        let client = client(&server).await;
+
+        assert!(matches!(
+            client.input(2024, 3).await,
+            Err(FetchError::NotPuzzleText(_))
+        ));
+        assert!(matches!(
+            client.input(2024, 4).await,
+            Err(FetchError::NotPuzzleText(line)) if line == "<!DOCTYPE html>"
+        ));
+    }
+    #[tokio::test]
+    async fn test_puzzle() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/2024/day/1")
+            .with_body(include_str!("../fixtures/puzzle_part_1.html"))
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/2024/day/2")
+            .with_body("Too many requests\n")
+            .create_async()
+            .await;
+        let client = client(&server).await;
+
+        assert!(client.puzzle(2024, 1).await.unwrap().contains("<article"));
+        assert!(matches!(
+            client.puzzle(2024, 2).await,
+            Err(FetchError::NotPuzzleText(_))
+        ));
+    }
+    #[tokio::test]
+    async fn test_whoami() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/")
+            .match_header("cookie", "session=abc")
+            .with_body(include_str!("../fixtures/logged_in.html"))
+            .create_async()
+            .await;
+        let client = client(&server).await;
+
+        assert_eq!(client.whoami().await.unwrap(), "Jane Doe");
+        mock.remove_async().await;
+
+        server
+            .mock("GET", "/")
+            .with_body(include_str!("../fixtures/puzzle_part_1.html"))
+            .create_async()
+            .await;
+        match client.whoami().await {
+            Err(e @ FetchError::LoggedOut) => assert!(e.is_fatal()),
+            result => panic!("expected to be logged out, got {result:?}"),
+        }
+    }
+    #[tokio::test]
+    async fn test_submit() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/2024/day/1/answer")
+            .match_body("level=2&answer=31")
+            .with_body("<main><article><p>That's the right answer!  You are one gold star closer.</p></article></main>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let outcome = client(&server).await.submit(2024, 1, 2, "31").await;
+
+        assert_eq!(outcome.unwrap(), Outcome::Correct);
+        mock.assert_async().await;
+    }
+}
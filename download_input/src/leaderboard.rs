@@ -0,0 +1,312 @@
+use crate::schedule;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime};
+
+/// The site asks for a private leaderboard to be fetched at most once every 15 minutes.
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Whether a leaderboard saved at `saved` is too recent to be fetched again.
+pub fn is_fresh(saved: SystemTime, now: SystemTime) -> bool {
+    now.duration_since(saved)
+        .is_ok_and(|age| age < REFRESH_INTERVAL)
+}
+
+/// A private leaderboard, as returned by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    event: String,
+    members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    name: Option<String>,
+    /// Times each part was solved, keyed by day and then part.
+    completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Star {
+    get_star_ts: i64,
+}
+
+impl Member {
+    /// Anonymous members are named after their id like on the site.
+    pub fn name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// When the member got the star for `part` of `day`, as a unix timestamp.
+    pub fn star(&self, day: u8, part: u8) -> Option<i64> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+        Some(star.get_star_ts)
+    }
+
+    /// One character a day: `*` for both parts, `+` for part 1 only and `.` for neither.
+    pub fn timeline(&self, days: u8) -> String {
+        (1..=days)
+            .map(|day| match (self.star(day, 1), self.star(day, 2)) {
+                (Some(_), Some(_)) => '*',
+                (Some(_), None) => '+',
+                _ => '.',
+            })
+            .collect()
+    }
+
+    // the last star up to and including `day`, which breaks ties in the scores
+    fn last_star(&self, day: u8) -> i64 {
+        self.completion_day_level
+            .range(..=day)
+            .flat_map(|(_, parts)| parts.values())
+            .map(|star| star.get_star_ts)
+            .max()
+            .unwrap_or(i64::MAX)
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn year(&self) -> u16 {
+        self.event.parse().unwrap_or_default()
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = &Member> {
+        self.members.values()
+    }
+
+    /// The last day anyone has a star for.
+    pub fn last_day(&self) -> u8 {
+        self.members()
+            .filter_map(|member| member.completion_day_level.keys().next_back())
+            .max()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Local scores counting the stars up to and including `day`. Each star is worth the number of
+    /// members for the first to get it, one less for the second and so on.
+    pub fn scores_after(&self, day: u8) -> BTreeMap<u64, u64> {
+        let mut scores: BTreeMap<u64, u64> = self.members().map(|member| (member.id, 0)).collect();
+        for day in 1..=day {
+            for part in 1..=2 {
+                let mut solved: Vec<(i64, u64)> = self
+                    .members()
+                    .filter_map(|member| Some((member.star(day, part)?, member.id)))
+                    .collect();
+                solved.sort();
+                for (place, (_, id)) in solved.into_iter().enumerate() {
+                    *scores.entry(id).or_default() += (self.members.len() - place) as u64;
+                }
+            }
+        }
+        scores
+    }
+
+    /// Members in the order of their scores after `day`, ties going to whoever got there first.
+    pub fn standings_after(&self, day: u8) -> Vec<(&Member, u64)> {
+        let scores = self.scores_after(day);
+        let mut standings: Vec<(&Member, u64)> = self
+            .members()
+            .map(|member| (member, scores[&member.id]))
+            .collect();
+        standings.sort_by_key(|&(member, score)| (std::cmp::Reverse(score), member.last_star(day)));
+        standings
+    }
+
+    /// The rank of every member after `day`, starting at 1.
+    pub fn ranks_after(&self, day: u8) -> BTreeMap<u64, usize> {
+        self.standings_after(day)
+            .into_iter()
+            .enumerate()
+            .map(|(i, (member, _))| (member.id, i + 1))
+            .collect()
+    }
+
+    /// Table of the standings with how each rank changed on the last day and each member's stars.
+    pub fn render_standings(&self) -> String {
+        let last_day = self.last_day();
+        let before = self.ranks_after(last_day.saturating_sub(1));
+
+        let rows: Vec<Vec<String>> = self
+            .standings_after(last_day)
+            .into_iter()
+            .enumerate()
+            .map(|(i, (member, score))| {
+                let change = before[&member.id] as i64 - (i + 1) as i64;
+                vec![
+                    (i + 1).to_string(),
+                    match change {
+                        0 => String::new(),
+                        change => format!("{change:+}"),
+                    },
+                    member.name(),
+                    score.to_string(),
+                    member.timeline(last_day),
+                ]
+            })
+            .collect();
+
+        let mut text = render(&["Rank", "Change", "Name", "Score", "Stars"], &rows);
+        text.push_str("Stars: * both parts, + part 1 only, . none\n");
+        text
+    }
+
+    /// Table of each member's rank after every day so far.
+    pub fn render_ranks(&self) -> String {
+        let last_day = self.last_day();
+        let ranks: Vec<BTreeMap<u64, usize>> =
+            (1..=last_day).map(|day| self.ranks_after(day)).collect();
+
+        let days: Vec<String> = (1..=last_day).map(|day| day.to_string()).collect();
+        let mut header = vec!["Name"];
+        header.extend(days.iter().map(String::as_str));
+
+        let rows: Vec<Vec<String>> = self
+            .standings_after(last_day)
+            .into_iter()
+            .map(|(member, _)| {
+                let mut row = vec![member.name()];
+                row.extend(ranks.iter().map(|ranks| ranks[&member.id].to_string()));
+                row
+            })
+            .collect();
+        render(&header, &rows)
+    }
+
+    /// Table of when each member solved both parts of `day`, counted from the unlock, with the time
+    /// part 2 took after part 1. The fastest part 2 comes first.
+    pub fn render_day(&self, day: u8) -> String {
+        let unlock = schedule::unlock_time(self.year(), day).timestamp();
+        let mut solved: Vec<(&Member, i64, Option<i64>)> = self
+            .members()
+            .filter_map(|member| Some((member, member.star(day, 1)?, member.star(day, 2))))
+            .collect();
+        solved.sort_by_key(|&(_, part_1, part_2)| {
+            (part_2.map_or(i64::MAX, |part_2| part_2 - part_1), part_1)
+        });
+
+        let rows: Vec<Vec<String>> = solved
+            .into_iter()
+            .map(|(member, part_1, part_2)| {
+                vec![
+                    member.name(),
+                    format_time(part_1 - unlock),
+                    part_2.map_or(String::new(), |part_2| format_time(part_2 - unlock)),
+                    part_2.map_or(String::new(), |part_2| format_time(part_2 - part_1)),
+                ]
+            })
+            .collect();
+        render(&["Name", "Part 1", "Part 2", "Delta"], &rows)
+    }
+}
+
+// hours, minutes and seconds
+fn format_time(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+fn render(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|cell| cell.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:width$}"))
+            .collect();
+        format!("{}\n", cells.join("  ").trim_end())
+    };
+
+    let mut text = line(header.to_vec());
+    for row in rows {
+        text.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leaderboard::{Leaderboard, REFRESH_INTERVAL, is_fresh};
+    use std::collections::BTreeMap;
+    use std::time::{Duration, SystemTime};
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::parse(include_str!("../fixtures/leaderboard.json")).unwrap()
+    }
+
+    #[test]
+    fn test_scores() {
+        let leaderboard = leaderboard();
+
+        assert_eq!(leaderboard.year(), 2024);
+        assert_eq!(leaderboard.last_day(), 3);
+        assert_eq!(
+            leaderboard.scores_after(1),
+            BTreeMap::from([(1, 5), (2, 5), (3, 1)])
+        );
+        assert_eq!(
+            leaderboard.scores_after(3),
+            BTreeMap::from([(1, 11), (2, 11), (3, 7)])
+        );
+        let ranks: Vec<Vec<usize>> = (1..=3)
+            .map(|day| leaderboard.ranks_after(day).into_values().collect())
+            .collect();
+        assert_eq!(ranks, [[1, 2, 3], [2, 1, 3], [2, 1, 3]]);
+    }
+    #[test]
+    fn test_render_standings() {
+        assert_eq!(
+            leaderboard().render_standings(),
+            "Rank  Change  Name                 Score  Stars\n\
+             1             Bob                  11     **.\n\
+             2             Alice                11     **+\n\
+             3             (anonymous user #3)  7      +.*\n\
+             Stars: * both parts, + part 1 only, . none\n"
+        );
+    }
+    #[test]
+    fn test_render_ranks() {
+        assert_eq!(
+            leaderboard().render_ranks(),
+            "Name                 1  2  3\n\
+             Bob                  2  1  1\n\
+             Alice                1  2  2\n\
+             (anonymous user #3)  3  3  3\n"
+        );
+    }
+    #[test]
+    fn test_render_day() {
+        assert_eq!(
+            leaderboard().render_day(2),
+            "Name   Part 1   Part 2   Delta\n\
+             Bob    0:08:20  0:11:40  0:03:20\n\
+             Alice  0:16:40  1:06:40  0:50:00\n"
+        );
+    }
+    #[test]
+    fn test_is_fresh() {
+        let saved = SystemTime::UNIX_EPOCH + Duration::from_secs(1_733_029_200);
+
+        assert!(is_fresh(saved, saved + Duration::from_secs(60)));
+        assert!(!is_fresh(saved, saved + REFRESH_INTERVAL));
+    }
+}
@@ -0,0 +1,15 @@
+//! Talks to the Advent of Code site for the binaries in this crate.
+
+pub mod client;
+pub mod leaderboard;
+pub mod puzzle;
+pub mod schedule;
+pub mod session;
+pub mod submit;
+
+use std::path::PathBuf;
+
+/// Directory of a day's crate, like `./2024/day_1`.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("./{year}/day_{day}"))
+}
@@ -1,70 +1,299 @@
-use reqwest::{Client, Response, StatusCode, Url};
-use std::path::PathBuf;
+use chrono::Local;
+use clap::Parser;
+use download_input::client::{self, AocClient, FetchError};
+use download_input::day_dir;
+use download_input::puzzle::{self, Puzzle};
+use download_input::schedule::{self, Clock, SystemClock};
+use download_input::session::Session;
+use download_input::submit::AnswersFile;
+use library::input::days_in;
+use reqwest::Url;
+use std::io::Write;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
 use std::str::FromStr;
+use std::time::Duration;
 use std::{fs, io};
+use tokio::time::Instant;
 
-const USER_AGENT: &str = "github.com/ThomasDavidson/Advent-of-Code by endershadow909@gmail.com";
+/// Time between requests to go easy on the server.
+const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Parser)]
+#[command(about = "Downloads puzzle inputs into ./{year}/day_{day}/input.txt")]
+struct Cli {
+    /// Year or range of years like 2023-2025, defaults to every year with a directory here
+    #[arg(value_parser = parse_range::<u16>)]
+    years: Option<RangeInclusive<u16>>,
+    /// Day or range of days like 1-12, defaults to every day of the year
+    #[arg(value_parser = parse_range::<u8>)]
+    days: Option<RangeInclusive<u8>>,
+    /// Download inputs again even if their input.txt already exists
+    #[arg(long)]
+    force: bool,
+    /// Also save each puzzle's description as puzzle.md and its code blocks as block_N.txt, fetched
+    /// again for part 2 once part 1's answer is recorded
+    #[arg(long)]
+    puzzle: bool,
+    /// Site to download from, like a local stand-in server for testing
+    #[arg(long, default_value = client::DEFAULT_BASE_URL)]
+    base_url: Url,
+    /// Wait for the next puzzle to unlock, counting down, then download its input and puzzle and
+    /// create the day's crate with `aoc new` if it doesn't have one
+    #[arg(long, conflicts_with_all = ["years", "days"])]
+    wait: bool,
+    /// Times to retry a request after a network or server error, waiting twice as long each time
+    #[arg(long, default_value_t = 3)]
+    retries: u32,
+}
+
+/// Parses a single value like `2025` or an inclusive range like `1-12`.
+fn parse_range<T>(text: &str) -> Result<RangeInclusive<T>, String>
+where
+    T: FromStr + PartialOrd + Copy,
+{
+    let parse = |value: &str| {
+        value
+            .trim()
+            .parse::<T>()
+            .map_err(|_| format!("'{value}' is not a number"))
+    };
+
+    let (start, end) = match text.split_once('-') {
+        Some((start, end)) => (parse(start)?, parse(end)?),
+        None => (parse(text)?, parse(text)?),
+    };
+    if start > end {
+        return Err(format!("{text} is an empty range"));
+    }
+    Ok(start..=end)
+}
+
+/// Years that already have a directory, like `./2024`.
+fn existing_years() -> io::Result<Vec<u16>> {
+    let mut years = Vec::new();
+    for entry in fs::read_dir(".")? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir()
+            && let Some(year) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse().ok())
+        {
+            years.push(year);
+        }
+    }
+    years.sort();
+    Ok(years)
+}
 
 fn generate_file_path(year: u16, day: u8) -> PathBuf {
-    PathBuf::from(format!("./{year}/day_{day}/input.txt"))
+    day_dir(year, day).join("input.txt")
+}
+
+/// What to download for a day.
+#[derive(Clone, Copy)]
+enum Download {
+    Input,
+    Puzzle,
 }
 
-fn generate_download_link(year: u16, day: u8) -> String {
-    format!("https://adventofcode.com/{year}/day/{day}/input")
+// missing, or saved before part 1 was solved and part 2 was shown
+fn needs_puzzle(dir: &Path) -> bool {
+    match fs::read_to_string(dir.join("puzzle.md")) {
+        Ok(markdown) => {
+            !puzzle::has_part_2(&markdown)
+                && AnswersFile::load(dir).is_ok_and(|answers| answers.correct(1).is_some())
+        }
+        Err(_) => true,
+    }
 }
 
-async fn request_input(session_cookie: &[u8], url: Url) -> Response {
-    Client::new()
-        .get(url)
-        .header("Cookie", session_cookie)
-        .header("User-Agent", USER_AGENT)
-        .send()
-        .await
-        .expect("Download failed, add session id to file if you haven't")
+/// Saves the description as `puzzle.md` and each code block as `block_{n}.txt` beside the day's
+/// `input.txt`. The block that looks like the example is also saved as `example.txt` if the day
+/// doesn't have one yet.
+fn save_puzzle(dir: &Path, puzzle: &Puzzle) -> io::Result<String> {
+    fs::write(dir.join("puzzle.md"), &puzzle.markdown)?;
+    for (i, example) in puzzle.examples.iter().enumerate() {
+        fs::write(dir.join(format!("block_{}.txt", i + 1)), example)?;
+    }
+
+    let mut saved = format!("puzzle saved with {} code blocks", puzzle.examples.len());
+    if let Some(primary) = puzzle.primary {
+        saved += &format!(", block {} looks like the example", primary + 1);
+        let example = dir.join("example.txt");
+        if !example.exists() {
+            fs::write(example, &puzzle.examples[primary])?;
+            saved += " and is saved as example.txt";
+        }
+    }
+    Ok(saved)
+}
+
+/// Creates the day's crate with the runner's `new` command, which keeps the files already downloaded.
+fn scaffold(year: u16, day: u8) -> io::Result<bool> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = Command::new(cargo)
+        .args(["run", "--quiet", "-p", "aoc", "--", "new"])
+        .args([year.to_string(), day.to_string()])
+        .status()?;
+    Ok(status.success())
 }
 
 #[tokio::main]
-async fn main() -> io::Result<()> {
-    let session_cookie = fs::read("session.txt")?;
+async fn main() -> io::Result<ExitCode> {
+    let cli = Cli::parse();
 
-    let years = 2023..=2024;
-    let days = 1..=25;
+    let clock = SystemClock;
+    let waiting_for = cli.wait.then(|| schedule::next_unlock(clock.now()));
 
+    let years: Vec<u16> = match (&cli.years, waiting_for) {
+        (_, Some(_)) => Vec::new(),
+        (Some(years), None) => years.clone().collect(),
+        (None, None) => existing_years()?,
+    };
+
+    let mut missing = Vec::new();
+    if let Some((year, day)) = waiting_for {
+        missing.push((year, day, Download::Input));
+        missing.push((year, day, Download::Puzzle));
+    }
     for year in years {
-        for day in days.clone() {
-            let path = generate_file_path(year, day);
-            let download_link = generate_download_link(year, day);
-
-            // remove file if it already exists
-            if let Ok(exists) = fs::exists(&path) {
-                if exists {
-                    fs::remove_file(&path)?;
-                }
+        let days = cli.days.clone().unwrap_or(1..=25);
+        for day in days.filter(|&day| day <= days_in(year)) {
+            if !cli.force && generate_file_path(year, day).exists() {
+                println!("{year} day {day}: already downloaded");
+            } else {
+                missing.push((year, day, Download::Input));
             }
+            if cli.puzzle && (cli.force || needs_puzzle(&day_dir(year, day))) {
+                missing.push((year, day, Download::Puzzle));
+            }
+        }
+    }
+    if missing.is_empty() {
+        return Ok(ExitCode::SUCCESS);
+    }
 
-            // get file
+    let session = match Session::load() {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("{e}");
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    let client = AocClient::new(cli.base_url, session).with_retries(cli.retries, REQUEST_INTERVAL);
 
-            let url = Url::from_str(&download_link).unwrap();
+    // an expired session would otherwise only show on the first download
+    match client.whoami().await {
+        Ok(user) => println!("Logged in as {user}"),
+        Err(e) => {
+            eprintln!("Can't check the session: {e}");
+            return Ok(ExitCode::FAILURE);
+        }
+    }
 
-            let response = request_input(&session_cookie, url).await;
+    let mut last_request = Instant::now();
 
-            if response.status() == StatusCode::BAD_REQUEST {
-                panic!("Bad Request: Check format of session file")
-            }
+    if let Some((year, day)) = waiting_for {
+        let unlock = schedule::unlock_time(year, day).with_timezone(&Local);
+        println!("{year} day {day} unlocks at {}", unlock.format("%F %T"));
+        schedule::wait_for_unlock(&clock, year, day, |left| {
+            let seconds = left.as_secs_f64().ceil() as u64;
+            print!(
+                "\r{:02}:{:02}:{:02} left",
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60
+            );
+            let _ = io::stdout().flush();
+        })
+        .await;
+        println!();
+    }
 
-            let text = response.text().await.unwrap();
+    let mut failed = false;
+    let mut unreleased = None;
+    for (year, day, download) in missing {
+        if unreleased == Some(year) {
+            continue;
+        }
+        // nothing is requested before it unlocks, the later days of the year unlock later still
+        if !schedule::is_unlocked(year, day, clock.now()) {
+            let unlock = schedule::unlock_time(year, day).with_timezone(&Local);
+            println!("{year} day {day}: unlocks at {}", unlock.format("%F %T"));
+            unreleased = Some(year);
+            continue;
+        }
+        tokio::time::sleep_until(last_request + REQUEST_INTERVAL).await;
+        last_request = Instant::now();
 
-            match fs::write(path, text) {
-                Err(_) => {
-                    println!("Can't write file check path");
-                    break;
-                }
-                Ok(_) => println!("Success"),
+        let result = match download {
+            Download::Input => client.input(year, day).await,
+            Download::Puzzle => client.puzzle(year, day).await,
+        };
+        let text = match result {
+            Ok(text) => text,
+            Err(FetchError::NotFound) => {
+                // later days of the year are not out either
+                println!("{year} day {day}: not released yet");
+                unreleased = Some(year);
+                continue;
             }
+            Err(e) if e.is_fatal() => {
+                eprintln!("{year} day {day}: {e}");
+                return Ok(ExitCode::FAILURE);
+            }
+            Err(e) => {
+                eprintln!("{year} day {day}: {e}");
+                failed = true;
+                continue;
+            }
+        };
 
-            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+        // an existing file is only replaced once the new one has arrived
+        let dir = day_dir(year, day);
+        fs::create_dir_all(&dir)?;
+        let saved = match download {
+            Download::Input => {
+                fs::write(generate_file_path(year, day), text).map(|_| "downloaded".to_string())
+            }
+            Download::Puzzle => save_puzzle(&dir, &Puzzle::parse(&text)),
+        };
+        match saved {
+            Err(e) => {
+                eprintln!("{year} day {day}: can't write to {}: {e}", dir.display());
+                failed = true;
+            }
+            Ok(saved) => println!("{year} day {day}: {saved}"),
         }
     }
 
-    Ok(())
+    if let Some((year, day)) = waiting_for
+        && !day_dir(year, day).join("Cargo.toml").exists()
+        && !scaffold(year, day)?
+    {
+        eprintln!("{year} day {day}: can't create the day's crate");
+        failed = true;
+    }
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_range;
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range::<u16>("2025"), Ok(2025..=2025));
+        assert_eq!(parse_range::<u8>("1-12"), Ok(1..=12));
+        assert!(parse_range::<u8>("12-1").is_err());
+        assert!(parse_range::<u8>("one").is_err());
+    }
 }
@@ -0,0 +1,103 @@
+use scraper::{ElementRef, Html, Selector};
+
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// A puzzle page as Markdown with the contents of its `<pre><code>` blocks. The page only has
+/// part 2's description once part 1 is solved.
+#[derive(Debug)]
+pub struct Puzzle {
+    pub markdown: String,
+    pub examples: Vec<String>,
+    /// Index in `examples` of the block that is most likely the puzzle's example input.
+    pub primary: Option<usize>,
+}
+
+impl Puzzle {
+    pub fn parse(page: &str) -> Self {
+        let document = Html::parse_document(page);
+        let articles = Selector::parse("article.day-desc").expect("selector is valid");
+        let blocks = Selector::parse("pre > code").expect("selector is valid");
+
+        let mut markdown = Vec::new();
+        let mut examples = Vec::new();
+        let mut primary = None;
+        for article in document.select(&articles) {
+            markdown.push(html2md::parse_html(&article.inner_html()));
+            for code in article.select(&blocks) {
+                let text: String = code.text().collect();
+                if primary.is_none() && follows_example_paragraph(code) && is_multiline(&text) {
+                    primary = Some(examples.len());
+                }
+                examples.push(text);
+            }
+        }
+        // without a "For example:" the first block of several lines is the best guess
+        let primary = primary.or_else(|| examples.iter().position(|text| is_multiline(text)));
+
+        Self {
+            markdown: markdown.join("\n\n") + "\n",
+            examples,
+            primary,
+        }
+    }
+
+    pub fn has_part_2(&self) -> bool {
+        has_part_2(&self.markdown)
+    }
+}
+
+/// Whether a saved description already holds part 2.
+pub fn has_part_2(markdown: &str) -> bool {
+    markdown.contains(PART_TWO_HEADING)
+}
+
+fn is_multiline(text: &str) -> bool {
+    text.trim_end().lines().count() > 1
+}
+
+// examples are introduced by a paragraph like "For example:"
+fn follows_example_paragraph(code: ElementRef) -> bool {
+    let paragraph = code
+        .parent()
+        .and_then(|pre| pre.prev_siblings().find_map(ElementRef::wrap));
+    paragraph.is_some_and(|paragraph| {
+        paragraph.value().name() == "p"
+            && paragraph
+                .text()
+                .collect::<String>()
+                .to_lowercase()
+                .contains("example")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::Puzzle;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_part_1() {
+        let puzzle = Puzzle::parse(include_str!("../fixtures/puzzle_part_1.html"));
+
+        assert_eq!(puzzle.examples, [EXAMPLE]);
+        assert_eq!(puzzle.primary, Some(0));
+        assert!(!puzzle.has_part_2());
+        assert!(
+            puzzle
+                .markdown
+                .contains("--- Day 1: Historian Hysteria ---")
+        );
+        assert!(puzzle.markdown.contains("*smallest*"));
+        assert!(!puzzle.markdown.contains("identify yourself"));
+    }
+    #[test]
+    fn test_part_2() {
+        let puzzle = Puzzle::parse(include_str!("../fixtures/puzzle_part_2.html"));
+
+        assert_eq!(puzzle.examples, ["a - b\n", EXAMPLE, EXAMPLE]);
+        assert_eq!(puzzle.primary, Some(1));
+        assert!(puzzle.has_part_2());
+        assert!(!puzzle.markdown.contains("Your puzzle answer"));
+    }
+}
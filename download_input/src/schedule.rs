@@ -0,0 +1,128 @@
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use library::input::days_in;
+use std::future::Future;
+use std::time::Duration;
+
+/// Waits end a moment after the unlock so a clock running slightly fast doesn't ask too early.
+const MARGIN: Duration = Duration::from_secs(1);
+
+/// Where the current time comes from, replaced by a fake clock in tests.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> {
+        tokio::time::sleep(duration)
+    }
+}
+
+/// Puzzles unlock at midnight US Eastern time on their day of December.
+pub fn unlock_time(year: u16, day: u8) -> DateTime<Utc> {
+    New_York
+        .with_ymd_and_hms(year.into(), 12, day.into(), 0, 0, 0)
+        .single()
+        .expect("midnight in December is unambiguous")
+        .with_timezone(&Utc)
+}
+
+pub fn is_unlocked(year: u16, day: u8, now: DateTime<Utc>) -> bool {
+    now >= unlock_time(year, day)
+}
+
+/// The year and day of the next puzzle to unlock after `now`.
+pub fn next_unlock(now: DateTime<Utc>) -> (u16, u8) {
+    let year = now.with_timezone(&New_York).year() as u16;
+    (year..)
+        .flat_map(|year| (1..=days_in(year)).map(move |day| (year, day)))
+        .find(|&(year, day)| !is_unlocked(year, day, now))
+        .expect("a later puzzle always unlocks")
+}
+
+/// Waits until the puzzle is unlocked, a second at a time, calling `countdown` with the time left
+/// before each second.
+pub async fn wait_for_unlock(
+    clock: &impl Clock,
+    year: u16,
+    day: u8,
+    mut countdown: impl FnMut(Duration),
+) {
+    let unlock = unlock_time(year, day) + MARGIN;
+    while let Ok(left) = (unlock - clock.now()).to_std() {
+        if left.is_zero() {
+            break;
+        }
+        countdown(left);
+        clock.sleep(left.min(Duration::from_secs(1))).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schedule::{Clock, is_unlocked, next_unlock, unlock_time, wait_for_unlock};
+    use chrono::{DateTime, TimeZone, Utc};
+    use std::cell::Cell;
+    use std::future::{self, Future};
+    use std::time::Duration;
+
+    struct FakeClock {
+        now: Cell<DateTime<Utc>>,
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) -> impl Future<Output = ()> {
+            self.now.set(self.now.get() + duration);
+            future::ready(())
+        }
+    }
+
+    fn utc(month: u32, day: u32, hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, month, day, hour, minute, second)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2024, 1), utc(12, 1, 5, 0, 0));
+        assert!(!is_unlocked(2024, 5, utc(12, 5, 4, 59, 59)));
+        assert!(is_unlocked(2024, 5, utc(12, 5, 5, 0, 0)));
+    }
+    #[test]
+    fn test_next_unlock() {
+        assert_eq!(next_unlock(utc(6, 1, 0, 0, 0)), (2024, 1));
+        assert_eq!(next_unlock(utc(12, 5, 4, 59, 59)), (2024, 5));
+        assert_eq!(next_unlock(utc(12, 5, 5, 0, 0)), (2024, 6));
+        assert_eq!(next_unlock(utc(12, 25, 5, 0, 0)), (2025, 1));
+        assert_eq!(
+            next_unlock(Utc.with_ymd_and_hms(2025, 12, 12, 5, 0, 0).unwrap()),
+            (2026, 1)
+        );
+    }
+    #[tokio::test]
+    async fn test_wait_for_unlock() {
+        let clock = FakeClock {
+            now: Cell::new(utc(12, 3, 4, 59, 57) + Duration::from_millis(500)),
+        };
+        let mut countdown = Vec::new();
+
+        wait_for_unlock(&clock, 2024, 3, |left| countdown.push(left.as_millis())).await;
+
+        assert_eq!(countdown, [3500, 2500, 1500, 500]);
+        assert_eq!(clock.now(), utc(12, 3, 5, 0, 1));
+
+        // an unlocked day doesn't wait at all
+        wait_for_unlock(&clock, 2024, 2, |_| panic!("waited for an unlocked day")).await;
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// Environment variable holding the session cookie, checked before any file.
+pub const ENV_VAR: &str = "AOC_SESSION";
+
+/// The `session` cookie of a logged in Advent of Code account.
+#[derive(Clone)]
+pub struct Session {
+    cookie: String,
+}
+
+#[derive(Debug)]
+pub enum SessionError {
+    /// No source had a session, with every place that was looked in.
+    Missing(Vec<String>),
+    Invalid {
+        source: String,
+        reason: String,
+    },
+    Unreadable {
+        source: String,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SessionError::Missing(sources) => write!(
+                f,
+                "no session cookie found, copy the session cookie of adventofcode.com into one of: {}",
+                sources.join(", ")
+            ),
+            SessionError::Invalid { source, reason } => {
+                write!(f, "the session in {source} is not valid: {reason}")
+            }
+            SessionError::Unreadable { source, error } => write!(f, "can't read {source}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+impl Session {
+    /// Accepts the cookie's value with or without the `session=` name, around any whitespace.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let value = text.trim();
+        let value = value.strip_prefix("session=").unwrap_or(value);
+        if value.is_empty() {
+            return Err("it is empty".to_string());
+        }
+        if let Some(c) = value
+            .chars()
+            .find(|c| !c.is_ascii_alphanumeric() && !"-_.%".contains(*c))
+        {
+            return Err(format!(
+                "it contains {c:?}, only the cookie's value is needed"
+            ));
+        }
+        Ok(Self {
+            cookie: format!("session={value}"),
+        })
+    }
+
+    /// Loads the session from the `AOC_SESSION` variable, then `session.txt` in the user's config
+    /// directory, like `~/.config/aoc/session.txt`, then `session.txt` in the working directory.
+    pub fn load() -> Result<Self, SessionError> {
+        let mut files = Vec::new();
+        if let Some(config) = dirs::config_dir() {
+            files.push(config.join("aoc").join("session.txt"));
+        }
+        files.push(PathBuf::from("session.txt"));
+
+        Self::load_from(env::var(ENV_VAR).ok(), &files)
+    }
+
+    fn load_from(variable: Option<String>, files: &[PathBuf]) -> Result<Self, SessionError> {
+        if let Some(text) = variable {
+            return Self::parse(&text).map_err(|reason| SessionError::Invalid {
+                source: ENV_VAR.to_string(),
+                reason,
+            });
+        }
+
+        for file in files {
+            match fs::read_to_string(file) {
+                Ok(text) => {
+                    return Self::parse(&text).map_err(|reason| SessionError::Invalid {
+                        source: display(file),
+                        reason,
+                    });
+                }
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(error) => {
+                    return Err(SessionError::Unreadable {
+                        source: display(file),
+                        error,
+                    });
+                }
+            }
+        }
+
+        let mut sources = vec![ENV_VAR.to_string()];
+        sources.extend(files.iter().map(|file| display(file)));
+        Err(SessionError::Missing(sources))
+    }
+
+    /// The value of the `Cookie` header.
+    pub fn cookie(&self) -> &str {
+        &self.cookie
+    }
+}
+
+// the cookie is kept out of debug output and logs
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Session(..)")
+    }
+}
+
+fn display(path: &Path) -> String {
+    path.display().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::session::{Session, SessionError};
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Session::parse("53616c7465640a\n").unwrap().cookie(),
+            "session=53616c7465640a"
+        );
+        assert_eq!(
+            Session::parse(" session=53616c7465640a ").unwrap().cookie(),
+            "session=53616c7465640a"
+        );
+        assert!(Session::parse("\n").is_err());
+        assert!(Session::parse("session=abc; other=1").is_err());
+    }
+    #[test]
+    fn test_load_from() {
+        let dir = std::env::temp_dir().join(format!("aoc_session_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("session.txt"), "abc\n").unwrap();
+        let files = [dir.join("missing.txt"), dir.join("session.txt")];
+
+        let from_variable = Session::load_from(Some("def".to_string()), &files);
+        let from_file = Session::load_from(None, &files);
+        let missing = Session::load_from(None, &files[..1]);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(from_variable.unwrap().cookie(), "session=def");
+        assert_eq!(from_file.unwrap().cookie(), "session=abc");
+        match missing {
+            Err(SessionError::Missing(sources)) => {
+                assert_eq!(sources.len(), 2);
+                assert!(sources[1].ends_with("missing.txt"));
+            }
+            result => panic!("expected a missing session, got {result:?}"),
+        }
+        assert!(matches!(
+            Session::load_from(Some(String::new()), &[PathBuf::new()]),
+            Err(SessionError::Invalid { .. })
+        ));
+    }
+}
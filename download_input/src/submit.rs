@@ -0,0 +1,277 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{fs, io};
+use toml_edit::{Array, DocumentMut, Item, Table, value};
+
+const ANSWERS_FILE: &str = "answers.toml";
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint, usually for answers that are not numbers.
+    Wrong,
+    /// Another answer was submitted too recently, with the wait left when the page said.
+    RateLimited(Option<Duration>),
+    /// The part was already solved, or part 1 is not solved yet.
+    AlreadySolved,
+    /// A page that was not recognized, with its text.
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Reads the outcome from the `<article>` of the page returned for a submission.
+    pub fn parse(page: &str) -> Self {
+        let text = article_text(page);
+
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Outcome::TooHigh
+            } else if text.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited(wait_left(&text))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown(text)
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited(Some(wait)) => {
+                write!(f, "answered too recently, wait {}s", wait.as_secs())
+            }
+            Outcome::RateLimited(None) => write!(f, "answered too recently"),
+            Outcome::AlreadySolved => write!(f, "not the current level, is it already solved?"),
+            Outcome::Unknown(text) => write!(f, "unrecognized response: {text}"),
+        }
+    }
+}
+
+// the text of the page's article with the tags removed and whitespace collapsed
+fn article_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// "You have 1m 25s left to wait."
+fn wait_left(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+
+    let mut seconds = 0;
+    for amount in wait.split_whitespace() {
+        let unit = match amount.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        let number: u64 = amount[..amount.len() - 1].parse().ok()?;
+        seconds += number * unit;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// A day's `answers.toml`, edited in place so hand-written comments and examples are kept.
+///
+/// Correct answers go in `[input]` where `aoc verify` reads them, wrong ones are listed in `[wrong]`
+/// so they are not submitted again:
+///
+/// ```toml
+/// [input]
+/// part_1 = 54927
+///
+/// [wrong]
+/// part_2 = ["54100", "54200"]
+/// ```
+pub struct AnswersFile {
+    path: PathBuf,
+    document: DocumentMut,
+}
+
+impl AnswersFile {
+    /// Loads the answers in `dir`, a missing file starts empty.
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        let path = dir.join(ANSWERS_FILE);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        Self::parse(path, &text)
+    }
+
+    fn parse(path: PathBuf, text: &str) -> io::Result<Self> {
+        let document = text
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Self { path, document })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        fs::write(&self.path, self.document.to_string())
+    }
+
+    /// The correct answer to part `level`, if it is known.
+    pub fn correct(&self, level: u8) -> Option<String> {
+        let answer = self.document.get("input")?.get(part_key(level))?;
+        match answer.as_integer() {
+            Some(number) => Some(number.to_string()),
+            None => answer.as_str().map(str::to_string),
+        }
+    }
+
+    pub fn is_known_wrong(&self, level: u8, answer: &str) -> bool {
+        self.document
+            .get("wrong")
+            .and_then(|wrong| wrong.get(part_key(level)))
+            .and_then(Item::as_array)
+            .is_some_and(|wrong| wrong.iter().any(|value| value.as_str() == Some(answer)))
+    }
+
+    /// Keeps a correct answer as the expected answer and adds a wrong one to the wrong answers.
+    pub fn record(&mut self, level: u8, answer: &str, outcome: &Outcome) {
+        if *outcome == Outcome::Correct {
+            // numbers are written as toml integers like the hand-written answers
+            let answer = match answer.parse::<i64>() {
+                Ok(number) => value(number),
+                Err(_) => value(answer),
+            };
+            table(&mut self.document, "input")[part_key(level).as_str()] = answer;
+        } else if outcome.is_wrong() && !self.is_known_wrong(level, answer) {
+            let wrong = table(&mut self.document, "wrong")
+                .entry(&part_key(level))
+                .or_insert(value(Array::new()));
+            if let Some(wrong) = wrong.as_array_mut() {
+                wrong.push(answer);
+            }
+        }
+    }
+}
+
+fn part_key(level: u8) -> String {
+    format!("part_{level}")
+}
+
+fn table<'a>(document: &'a mut DocumentMut, name: &str) -> &'a mut Table {
+    document
+        .entry(name)
+        .or_insert(toml_edit::table())
+        .as_table_mut()
+        .expect("answer sections are tables")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::submit::{AnswersFile, Outcome};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_outcomes() {
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+            )),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data."
+            )),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer.  If you're stuck, try again."
+            )),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a>"
+            )),
+            Outcome::AlreadySolved
+        );
+    }
+    #[test]
+    fn test_rate_limited() {
+        assert_eq!(
+            Outcome::parse(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 25s left to wait."
+            )),
+            Outcome::RateLimited(Some(Duration::from_secs(85)))
+        );
+        assert_eq!(
+            Outcome::parse(&page("You gave an answer too recently.")),
+            Outcome::RateLimited(None)
+        );
+    }
+    #[test]
+    fn test_record() {
+        let mut answers = AnswersFile::parse(
+            PathBuf::from("answers.toml"),
+            "# from the puzzle\n[example]\npart_1 = 11\n",
+        )
+        .unwrap();
+
+        answers.record(1, "1882714", &Outcome::Correct);
+        answers.record(2, "100", &Outcome::TooHigh);
+        answers.record(2, "100", &Outcome::TooHigh);
+        answers.record(2, "5", &Outcome::RateLimited(None));
+
+        assert_eq!(answers.correct(1), Some("1882714".to_string()));
+        assert_eq!(answers.correct(2), None);
+        assert!(answers.is_known_wrong(2, "100"));
+        assert!(!answers.is_known_wrong(2, "5"));
+        assert_eq!(
+            answers.document.to_string(),
+            "# from the puzzle\n[example]\npart_1 = 11\n\n[input]\npart_1 = 1882714\n\n[wrong]\npart_2 = [\"100\"]\n"
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+#[path = "../../src/input/toml_answer.rs"]
+mod toml_answer;
+
+use toml_answer::Answer;
+
+const FILE_NAME: &str = "examples.toml";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    #[serde(default)]
+    examples: Vec<Example>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Example {
+    file: String,
+    /// Used for the test names instead of the file name.
+    name: Option<String>,
+    part_1: Option<Answer>,
+    part_2: Option<Answer>,
+    #[serde(default)]
+    params: toml::Table,
+    /// Why the day gets this example wrong, its tests are generated but ignored.
+    ignore: Option<String>,
+}
+
+/// One test to generate.
+#[derive(Debug, PartialEq)]
+struct Case {
+    name: String,
+    file: String,
+    part: u8,
+    params: String,
+    expected: String,
+    ignore: Option<String>,
+}
+
+/// Generates a `#[test]` for every answer in the `examples.toml` next to the crate's `Cargo.toml`,
+/// called with the day to run: `examples!(crate::DAY)`.
+#[proc_macro]
+pub fn examples(day: TokenStream) -> TokenStream {
+    let day = TokenStream2::from(day);
+    match expand(day) {
+        Ok(tokens) => tokens.into(),
+        Err(message) => quote!(compile_error!(#message);).into(),
+    }
+}
+
+fn expand(day: TokenStream2) -> Result<TokenStream2, String> {
+    let dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|e| e.to_string())?;
+    let dir = Path::new(&dir);
+    let manifest_path = dir.join(FILE_NAME);
+    let text = fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("could not read {}: {e}", manifest_path.display()))?;
+    let cases = cases(&text)?;
+
+    let manifest_path = manifest_path.display().to_string();
+    let tests = cases.iter().map(|case| {
+        let name = format_ident!("{}", case.name);
+        let file = dir.join(&case.file).display().to_string();
+        let part = match case.part {
+            1 => quote!(::library::input::Part::One),
+            _ => quote!(::library::input::Part::Two),
+        };
+        let Case {
+            params,
+            expected,
+            ignore,
+            ..
+        } = case;
+        let ignore = ignore.as_ref().map(|reason| quote!(#[ignore = #reason]));
+
+        quote! {
+            #[test]
+            #ignore
+            fn #name() {
+                ::library::input::examples::check(#day, include_str!(#file), #part, #params, #expected);
+            }
+        }
+    });
+
+    Ok(quote! {
+        // rebuilds the tests when the manifest changes
+        const _: &str = include_str!(#manifest_path);
+        #(#tests)*
+    })
+}
+
+fn cases(text: &str) -> Result<Vec<Case>, String> {
+    let manifest: Manifest = toml::from_str(text).map_err(|e| format!("{FILE_NAME}: {e}"))?;
+
+    let mut cases = Vec::new();
+    let mut names = HashSet::new();
+    for example in manifest.examples {
+        let base = example
+            .name
+            .as_deref()
+            .unwrap_or_else(|| example.file.strip_suffix(".txt").unwrap_or(&example.file));
+        let base = to_identifier(base);
+        let params = toml::to_string(&example.params).map_err(|e| e.to_string())?;
+
+        for (part, answer) in [(1, &example.part_1), (2, &example.part_2)] {
+            let Some(answer) = answer else {
+                continue;
+            };
+            let name = format!("{base}_part_{part}");
+            if !names.insert(name.clone()) {
+                return Err(format!(
+                    "{FILE_NAME}: more than one test named {name}, give the examples a `name`"
+                ));
+            }
+
+            cases.push(Case {
+                name,
+                file: example.file.clone(),
+                part,
+                params: params.clone(),
+                expected: answer.render(),
+                ignore: example.ignore.clone(),
+            });
+        }
+    }
+    Ok(cases)
+}
+
+fn to_identifier(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Case, cases};
+
+    #[test]
+    fn test_cases() {
+        let cases = cases(
+            r#"examples = [
+                { file = "example.txt", part_1 = 374, part_2 = "8,410" },
+                { file = "example.txt", name = "wide", part_2 = 1030, params = { expansion = 10 } },
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(cases.len(), 3);
+        assert_eq!(cases[1].expected, "8,410");
+        assert_eq!(
+            cases[2],
+            Case {
+                name: "wide_part_2".to_string(),
+                file: "example.txt".to_string(),
+                part: 2,
+                params: "expansion = 10\n".to_string(),
+                expected: "1030".to_string(),
+                ignore: None,
+            }
+        );
+    }
+    #[test]
+    fn test_ignore() {
+        let cases = cases(
+            r#"examples = [
+                { file = "example.txt", part_1 = 46, part_2 = 51, ignore = "panics on a splitter" },
+            ]"#,
+        )
+        .unwrap();
+
+        assert!(
+            cases
+                .iter()
+                .all(|case| case.ignore.as_deref() == Some("panics on a splitter"))
+        );
+    }
+    #[test]
+    fn test_duplicate_names() {
+        let error = cases(
+            r#"examples = [
+                { file = "example.txt", part_1 = 16, params = { steps = 6 } },
+                { file = "example.txt", part_1 = 50, params = { steps = 10 } },
+            ]"#,
+        )
+        .unwrap_err();
+
+        assert!(error.contains("example_part_1"), "{error}");
+    }
+}
@@ -0,0 +1,343 @@
+use crate::grid::{Coord, Direction, Direction8, Vec2};
+use crate::input::AocError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Something a [`Grid`] can be indexed with. Signed positions left of or above the grid are
+/// outside every grid.
+pub trait Position: Copy + fmt::Debug {
+    fn to_coord(self) -> Option<Coord>;
+}
+
+impl Position for Coord {
+    fn to_coord(self) -> Option<Coord> {
+        Some(self)
+    }
+}
+
+macro_rules! impl_position {
+    ($($t:ty),*) => {
+        $(
+            impl Position for Vec2<$t> {
+                fn to_coord(self) -> Option<Coord> {
+                    Some(Coord::new(
+                        usize::try_from(self.x).ok()?,
+                        usize::try_from(self.y).ok()?,
+                    ))
+                }
+            }
+        )*
+    };
+}
+impl_position!(usize, isize, i32, i64);
+
+/// A rectangle of tiles stored row by row, `x` going right and `y` going down from the top left.
+/// A map kept as `Vec<Vec<T>>` is turned into one with [`Grid::from_rows`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    tiles: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, tile: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            tiles: vec![tile; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Fails if the rows are not all as long as the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut tiles = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(AocError::new(format!(
+                    "expected a row of {width} tiles, found {}",
+                    row.len()
+                ))
+                .at_line(y + 1));
+            }
+            tiles.extend(row);
+        }
+
+        Ok(Self {
+            tiles,
+            width,
+            height,
+        })
+    }
+
+    /// Parses one tile per character, one row per line. Blank lines at the end are ignored.
+    pub fn parse(input: &str, mut tile: impl FnMut(char) -> T) -> Result<Self, AocError> {
+        Self::try_parse(input, |c| Ok(tile(c)))
+    }
+
+    /// Like [`Grid::parse`] for tiles that can be invalid, the error is given the tile's line and column.
+    pub fn try_parse(
+        input: &str,
+        mut tile: impl FnMut(char) -> Result<T, AocError>,
+    ) -> Result<Self, AocError> {
+        let rows = input
+            .trim_end_matches(['\n', '\r'])
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| tile(c).map_err(|e| e.at_line(y + 1).at_column(x + 1)))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, at: impl Position) -> Option<usize> {
+        let at = at.to_coord()?;
+        (at.x < self.width && at.y < self.height).then_some(at.y * self.width + at.x)
+    }
+
+    pub fn contains(&self, at: impl Position) -> bool {
+        self.index_of(at).is_some()
+    }
+
+    pub fn get(&self, at: impl Position) -> Option<&T> {
+        self.tiles.get(self.index_of(at)?)
+    }
+
+    pub fn get_mut(&mut self, at: impl Position) -> Option<&mut T> {
+        let i = self.index_of(at)?;
+        self.tiles.get_mut(i)
+    }
+
+    // the tiles row by row
+    pub(crate) fn into_tiles(self) -> Vec<T> {
+        self.tiles
+    }
+
+    fn coord_of(&self, i: usize) -> Coord {
+        Coord::new(i % self.width, i / self.width)
+    }
+
+    /// Every coordinate, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + '_ {
+        (0..self.tiles.len()).map(|i| self.coord_of(i))
+    }
+
+    /// Every tile with its coordinate, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(&self.tiles)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> {
+        let width = self.width;
+        self.tiles
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, tile)| (Coord::new(i % width, i / width), tile))
+    }
+
+    /// The first tile equal to `tile`, row by row, like the start of a maze.
+    pub fn find(&self, tile: &T) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        let i = self.tiles.iter().position(|found| found == tile)?;
+        Some(self.coord_of(i))
+    }
+
+    /// Every tile equal to `tile`, row by row.
+    pub fn find_all(&self, tile: &T) -> Vec<Coord>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(|&(_, found)| found == tile)
+            .map(|(coord, _)| coord)
+            .collect()
+    }
+
+    /// The neighbours north, east, south and west of `at` that are inside the grid.
+    pub fn neighbours(&self, at: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::MOVE
+            .into_iter()
+            .filter_map(move |direction| (at + direction).ok())
+            .filter(|&next| self.contains(next))
+    }
+
+    /// The neighbours of `at` that are inside the grid, diagonals included, clockwise from north.
+    pub fn neighbours_8(&self, at: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| (at + direction).ok())
+            .filter(|&next| self.contains(next))
+    }
+
+    /// The coordinates from `at` to the edge of the grid going `direction`, starting with `at`.
+    pub fn ray(&self, at: Coord, direction: Direction8) -> impl Iterator<Item = Coord> + '_ {
+        std::iter::successors(Some(at), move |&next| (next + direction).ok())
+            .take_while(|&next| self.contains(next))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.tiles[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.tiles[y * self.width..(y + 1) * self.width])
+    }
+
+    /// The tiles of column `x` from top to bottom, none if it is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.tiles[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// A grid of the same size with every tile turned into another, like a grid of visited flags.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            tiles: self.tiles.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T, P: Position> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, at: P) -> &T {
+        match self.get(at) {
+            Some(tile) => tile,
+            None => panic!("{at:?} is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T, P: Position> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, at: P) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(at) {
+            Some(tile) => tile,
+            None => panic!("{at:?} is outside the {width}x{height} grid"),
+        }
+    }
+}
+
+// the grid as it was parsed, one line per row
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{tile}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Coord, Direction8, Grid, Vec2};
+    use crate::input::AocError;
+
+    const INPUT: &str = "#.#\n.S.\n##.\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(INPUT, |c| c).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[Coord::new(1, 1)], 'S');
+        assert_eq!(grid.get(Vec2::new(2, 2)), Some(&'.'));
+        assert_eq!(grid.get(Vec2::new(-1, 0)), None);
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+
+        let ragged = Grid::parse("##\n#\n", |c| c).unwrap_err();
+        assert_eq!(ragged.line, Some(2));
+        let invalid = Grid::try_parse("..\n.x", |c| match c {
+            '.' => Ok(0),
+            c => Err(AocError::new(format!("invalid tile {c}"))),
+        })
+        .unwrap_err();
+        assert_eq!((invalid.line, invalid.column), (Some(2), Some(2)));
+    }
+    #[test]
+    fn test_find() {
+        let mut grid = Grid::parse(INPUT, |c| c).unwrap();
+
+        assert_eq!(grid.find(&'S'), Some(Coord::new(1, 1)));
+        assert_eq!(grid.find(&'x'), None);
+        assert_eq!(
+            grid.find_all(&'#'),
+            [
+                Coord::new(0, 0),
+                Coord::new(2, 0),
+                Coord::new(0, 2),
+                Coord::new(1, 2)
+            ]
+        );
+        grid[Coord::new(1, 1)] = '.';
+        assert_eq!(grid.find(&'S'), None);
+    }
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, 0);
+
+        assert_eq!(
+            grid.neighbours(Coord::new(0, 0)).collect::<Vec<_>>(),
+            [Coord::new(1, 0), Coord::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours(Coord::new(1, 1)).count(), 3);
+        assert_eq!(
+            grid.neighbours_8(Coord::new(2, 1)).collect::<Vec<_>>(),
+            [Coord::new(2, 0), Coord::new(1, 1), Coord::new(1, 0)]
+        );
+        assert_eq!(
+            grid.ray(Coord::new(0, 1), Direction8::NorthEast)
+                .collect::<Vec<_>>(),
+            [Coord::new(0, 1), Coord::new(1, 0)]
+        );
+    }
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse(INPUT, |c| c).unwrap();
+
+        assert_eq!(grid.row(2), Some(&['#', '#', '.'][..]));
+        assert_eq!(grid.row(3), None);
+        assert_eq!(grid.rows().count(), 3);
+        let column: String = grid.column(0).collect();
+        assert_eq!(column, "#.#");
+        assert_eq!(grid.column(3).count(), 0);
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["#.#", ".S#", "#.."]);
+        assert_eq!(
+            grid.map(|&c| c == '#')
+                .iter()
+                .filter(|(_, &wall)| wall)
+                .count(),
+            4
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::grid::{Direction, GridState, UVec2, Vec2};
+use num::{one, zero, One, Zero};
+use std::ops::{Add, Mul, Neg};
+
+/// The eight points of the compass, for grids where diagonal steps count as neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+    pub const CARDINAL: [Direction8; 4] = [
+        Direction8::North,
+        Direction8::East,
+        Direction8::South,
+        Direction8::West,
+    ];
+    pub const DIAGONAL: [Direction8; 4] = [
+        Direction8::NorthEast,
+        Direction8::SouthEast,
+        Direction8::SouthWest,
+        Direction8::NorthWest,
+    ];
+
+    pub const fn is_diagonal(self) -> bool {
+        self as u8 % 2 == 1
+    }
+
+    // turns by `eighths` of a full turn clockwise
+    const fn turn(self, eighths: u8) -> Self {
+        Self::ALL[(self as usize + eighths as usize) % 8]
+    }
+
+    /// Turns 45° clockwise.
+    pub const fn rotate_right(self) -> Self {
+        self.turn(1)
+    }
+
+    /// Turns 45° anticlockwise.
+    pub const fn rotate_left(self) -> Self {
+        self.turn(7)
+    }
+
+    /// Turns 90° clockwise.
+    pub const fn right(self) -> Self {
+        self.turn(2)
+    }
+
+    /// Turns 90° anticlockwise.
+    pub const fn left(self) -> Self {
+        self.turn(6)
+    }
+
+    pub const fn inverse(self) -> Self {
+        self.turn(4)
+    }
+
+    /// The step taken in this direction, `y` growing southwards like [`Direction::get_translation`].
+    pub fn get_translation<T>(self) -> (T, T)
+    where
+        T: Zero + One + Neg<Output = T>,
+    {
+        match self {
+            Direction8::North => (zero(), -one::<T>()),
+            Direction8::NorthEast => (one(), -one::<T>()),
+            Direction8::East => (one(), zero()),
+            Direction8::SouthEast => (one(), one()),
+            Direction8::South => (zero(), one()),
+            Direction8::SouthWest => (-one::<T>(), one()),
+            Direction8::West => (-one::<T>(), zero()),
+            Direction8::NorthWest => (-one::<T>(), -one::<T>()),
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = &'static str;
+
+    fn try_from(direction: Direction8) -> Result<Self, Self::Error> {
+        match direction {
+            Direction8::North => Ok(Direction::North),
+            Direction8::East => Ok(Direction::East),
+            Direction8::South => Ok(Direction::South),
+            Direction8::West => Ok(Direction::West),
+            _ => Err("a diagonal has no Direction"),
+        }
+    }
+}
+
+impl TryFrom<Direction> for Direction8 {
+    type Error = &'static str;
+
+    fn try_from(direction: Direction) -> Result<Self, Self::Error> {
+        match direction {
+            Direction::North => Ok(Direction8::North),
+            Direction::East => Ok(Direction8::East),
+            Direction::South => Ok(Direction8::South),
+            Direction::West => Ok(Direction8::West),
+            Direction::None => Err("Direction::None has no compass point"),
+        }
+    }
+}
+
+impl<T: Zero + One + Clone + Neg<Output = T>> Mul<T> for Direction8 {
+    type Output = Vec2<T>;
+
+    fn mul(self, rhs: T) -> Vec2<T> {
+        let (x, y): (T, T) = self.get_translation();
+
+        Vec2 {
+            x: x * rhs.clone(),
+            y: y * rhs,
+        }
+    }
+}
+
+impl<T: Neg<Output = T> + Add + Zero + One> Add<Direction8> for Vec2<T> {
+    type Output = Vec2<T>;
+    fn add(self, direction: Direction8) -> Self::Output {
+        let (offset_x, offset_y): (T, T) = direction.get_translation();
+
+        Self {
+            x: self.x + offset_x,
+            y: self.y + offset_y,
+        }
+    }
+}
+
+impl Add<Direction8> for UVec2<usize> {
+    type Output = Result<UVec2<usize>, &'static str>;
+
+    fn add(self, direction: Direction8) -> Self::Output {
+        let (offset_x, offset_y): (isize, isize) = direction.get_translation();
+
+        match (
+            self.x.checked_add_signed(offset_x),
+            self.y.checked_add_signed(offset_y),
+        ) {
+            (Some(x), Some(y)) => Ok(UVec2 { x, y }),
+            _ => Err("error"),
+        }
+    }
+}
+
+impl Add<Direction8> for GridState {
+    type Output = Result<GridState, ()>;
+
+    fn add(self, direction: Direction8) -> Self::Output {
+        self + direction * 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Coord, Direction, Direction8, GridState, Vec2};
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(Direction8::North.rotate_right(), Direction8::NorthEast);
+        assert_eq!(Direction8::North.rotate_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::NorthWest.right(), Direction8::NorthEast);
+        assert_eq!(Direction8::East.left(), Direction8::North);
+        assert_eq!(Direction8::SouthWest.inverse(), Direction8::NorthEast);
+        assert!(Direction8::DIAGONAL.iter().all(|d| d.is_diagonal()));
+        assert!(!Direction8::CARDINAL.iter().any(|d| d.is_diagonal()));
+    }
+    #[test]
+    fn test_translation() {
+        assert_eq!(Vec2::new(2, 2) + Direction8::NorthWest, Vec2::new(1, 1));
+        assert_eq!(Direction8::SouthEast * 3, Vec2::new(3, 3));
+        assert_eq!(
+            Coord::new(1, 0) + Direction8::SouthWest,
+            Ok(Coord::new(0, 1))
+        );
+        assert!((Coord::new(1, 0) + Direction8::NorthEast).is_err());
+
+        let state = GridState::new(1, 1, Direction::East);
+        assert_eq!(
+            state + Direction8::NorthEast,
+            Ok(GridState::new(2, 0, Direction::East))
+        );
+        assert_eq!(
+            GridState::new(0, 0, Direction::East) + Direction8::West,
+            Err(())
+        );
+    }
+    #[test]
+    fn test_conversions() {
+        for direction in Direction::MOVE {
+            let compass = Direction8::try_from(direction).unwrap();
+            assert_eq!(Direction::try_from(compass), Ok(direction));
+            assert_eq!(
+                compass.get_translation::<i32>(),
+                direction.get_translation::<i32>()
+            );
+        }
+        assert!(Direction8::try_from(Direction::None).is_err());
+        assert!(Direction::try_from(Direction8::NorthEast).is_err());
+    }
+}
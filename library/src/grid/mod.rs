@@ -1,10 +1,21 @@
+mod container;
+mod direction8;
+mod notation;
+mod sparse;
+mod view;
+
+pub use container::{Grid, Position};
+pub use direction8::Direction8;
 use itertools::Itertools;
+pub use notation::{Notation, Turn};
 use num::{one, zero, One, Zero};
+pub use sparse::SparseGrid;
 use std::{
     fmt::Debug,
     ops::{Add, Mul, Neg, Sub},
     str::FromStr,
 };
+pub use view::{Tiled, Wrapping};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DirectionFilter {
@@ -197,6 +208,21 @@ macro_rules! filter_direction {
         }
     };
 }
+#[test]
+fn test_vec3_parse() {
+    assert_eq!(
+        Vec3::<i64>::parse("19, 13, -30"),
+        Some(Vec3 {
+            x: 19,
+            y: 13,
+            z: -30
+        })
+    );
+    assert_eq!(Vec3::<i64>::parse("19, 13"), None);
+    assert_eq!(Vec3::<i64>::parse("19, 13, 30, 1"), None);
+    assert_eq!(Vec3::<i64>::parse("19, x, 30"), None);
+}
+
 #[test]
 fn test_filter() {
     let f = filter_direction!([
@@ -308,6 +334,7 @@ impl Add<Direction> for UVec2<usize> {
 
 pub type Coord = UVec2<usize>;
 
+#[deprecated(note = "parse the map into a `Grid` and use `Grid::find_all`")]
 pub fn find_in_coord<T>(map: &[Vec<T>], find: &T) -> Vec<Coord>
 where
     T: PartialEq<T>,
@@ -386,18 +413,17 @@ pub struct Vec3<T> {
     pub z: T,
 }
 impl<T: FromStr> Vec3<T> {
-    pub fn parse(str: &str) -> Option<Self>
-    where
-        <T as FromStr>::Err: Debug,
-    {
+    pub fn parse(str: &str) -> Option<Self> {
         let (x, y, z) = str
             .split(",")
-            .map(|str| str.split_whitespace().collect::<String>())
-            .map(|str| str.parse().unwrap())
-            .collect_tuple()
-            .unwrap();
+            .map(|str| str.split_whitespace().collect::<String>().parse().ok())
+            .collect_tuple()?;
 
-        Some(Self { x, y, z })
+        Some(Self {
+            x: x?,
+            y: y?,
+            z: z?,
+        })
     }
 }
 impl<T: Sub<Output = T>> Sub for Vec3<T> {
@@ -0,0 +1,235 @@
+use crate::grid::Direction;
+use crate::input::AocError;
+use std::fmt;
+use std::str::FromStr;
+
+/// The ways puzzles write a direction as one character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// `^>v<`, with `o` for [`Direction::None`], as printed by [`Direction::to_char`].
+    Arrow,
+    /// `UDLR` for up, down, left and right, in either case.
+    Udlr,
+    /// `NESW`, in either case.
+    Compass,
+    /// `0123` clockwise from east, like the hex codes of 2023 day 18.
+    Digit,
+}
+
+impl Notation {
+    pub const ALL: [Notation; 4] = [
+        Notation::Arrow,
+        Notation::Udlr,
+        Notation::Compass,
+        Notation::Digit,
+    ];
+}
+
+impl fmt::Display for Notation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Notation::Arrow => "arrow",
+            Notation::Udlr => "UDLR",
+            Notation::Compass => "compass",
+            Notation::Digit => "digit",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Direction {
+    /// Reads `c` written in `notation`, only arrows have a character for [`Direction::None`].
+    pub fn from_char_in(c: char, notation: Notation) -> Result<Self, AocError> {
+        let direction = match (notation, c.to_ascii_uppercase()) {
+            (Notation::Arrow, _) => match c {
+                '^' => Some(Direction::North),
+                '>' => Some(Direction::East),
+                'v' => Some(Direction::South),
+                '<' => Some(Direction::West),
+                'o' => Some(Direction::None),
+                _ => None,
+            },
+            (Notation::Udlr, 'U') | (Notation::Compass, 'N') | (Notation::Digit, '3') => {
+                Some(Direction::North)
+            }
+            (Notation::Udlr, 'R') | (Notation::Compass, 'E') | (Notation::Digit, '0') => {
+                Some(Direction::East)
+            }
+            (Notation::Udlr, 'D') | (Notation::Compass, 'S') | (Notation::Digit, '1') => {
+                Some(Direction::South)
+            }
+            (Notation::Udlr, 'L') | (Notation::Compass, 'W') | (Notation::Digit, '2') => {
+                Some(Direction::West)
+            }
+            _ => None,
+        };
+
+        direction.ok_or_else(|| AocError::new(format!("{c:?} is not a {notation} direction")))
+    }
+
+    /// Writes the direction in `notation`, the inverse of [`Direction::from_char_in`]. Letters are
+    /// upper case.
+    pub const fn to_char_in(self, notation: Notation) -> Option<char> {
+        let chars = match notation {
+            Notation::Arrow => return Some(self.to_char()),
+            Notation::Udlr => ['U', 'R', 'D', 'L'],
+            Notation::Compass => ['N', 'E', 'S', 'W'],
+            Notation::Digit => ['3', '0', '1', '2'],
+        };
+        match self {
+            Direction::North => Some(chars[0]),
+            Direction::East => Some(chars[1]),
+            Direction::South => Some(chars[2]),
+            Direction::West => Some(chars[3]),
+            Direction::None => None,
+        }
+    }
+
+    pub const fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.left(),
+            Turn::Right => self.right(),
+            Turn::Forward => self,
+        }
+    }
+}
+
+// no character means different directions in two notations, so the first one that reads `c` is
+// right. Case folding only applies to letters, `V` is not an arrow and reads in no notation.
+impl TryFrom<char> for Direction {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Notation::ALL
+            .into_iter()
+            .find_map(|notation| Direction::from_char_in(c, notation).ok())
+            .ok_or_else(|| AocError::new(format!("{c:?} is not a direction")))
+    }
+}
+
+/// One character in any [`Notation`], or a word like `north` or `up`.
+impl FromStr for Direction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Direction::try_from(c);
+        }
+
+        match s.to_ascii_lowercase().as_str() {
+            "north" | "up" => Ok(Direction::North),
+            "east" | "right" => Ok(Direction::East),
+            "south" | "down" => Ok(Direction::South),
+            "west" | "left" => Ok(Direction::West),
+            "none" => Ok(Direction::None),
+            _ => Err(AocError::new(format!("{s:?} is not a direction"))),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// A turn relative to the current heading, written `L`, `R` or `F`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Forward,
+}
+
+impl Turn {
+    pub const fn to_char(self) -> char {
+        match self {
+            Turn::Left => 'L',
+            Turn::Right => 'R',
+            Turn::Forward => 'F',
+        }
+    }
+}
+
+impl TryFrom<char> for Turn {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_uppercase() {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            'F' => Ok(Turn::Forward),
+            _ => Err(AocError::new(format!("{c:?} is not a turn"))),
+        }
+    }
+}
+
+impl FromStr for Turn {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Turn::try_from(c),
+            _ => Err(AocError::new(format!("{s:?} is not a turn"))),
+        }
+    }
+}
+
+impl fmt::Display for Turn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Direction, Notation, Turn};
+
+    #[test]
+    fn test_round_trip() {
+        for notation in Notation::ALL {
+            for direction in Direction::MOVE {
+                let c = direction.to_char_in(notation).unwrap();
+                assert_eq!(Direction::from_char_in(c, notation), Ok(direction));
+                assert_eq!(Direction::try_from(c), Ok(direction));
+                assert_eq!(c.to_string().parse(), Ok(direction));
+            }
+        }
+        assert_eq!(Direction::None.to_char_in(Notation::Arrow), Some('o'));
+        assert_eq!(Direction::None.to_char_in(Notation::Udlr), None);
+        assert_eq!(Direction::South.to_string(), "v");
+    }
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Direction::from_char_in('u', Notation::Udlr),
+            Ok(Direction::North)
+        );
+        assert_eq!(
+            Direction::from_char_in('0', Notation::Digit),
+            Ok(Direction::East)
+        );
+        assert!(Direction::from_char_in('N', Notation::Udlr).is_err());
+        assert!(Direction::from_char_in('V', Notation::Arrow).is_err());
+        assert!(Direction::try_from('x').is_err());
+        assert!(Direction::try_from('V').is_err());
+        assert_eq!("West".parse(), Ok(Direction::West));
+        assert_eq!("down".parse(), Ok(Direction::South));
+        assert!("sideways".parse::<Direction>().is_err());
+    }
+    #[test]
+    fn test_turn() {
+        let turns: Vec<Turn> = "LFRR".chars().map(|c| c.try_into().unwrap()).collect();
+        let heading = turns.iter().fold(Direction::North, |d, &t| d.turn(t));
+
+        assert_eq!(heading, Direction::East);
+        assert_eq!(
+            turns.iter().map(Turn::to_string).collect::<String>(),
+            "LFRR"
+        );
+        assert!(Turn::try_from('B').is_err());
+        assert_eq!("r".parse(), Ok(Turn::Right));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::grid::{Direction, Grid, Vec2};
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+
+/// Tiles at any signed position, for maps that grow in every direction like a flood fill from
+/// the start. The bounding box grows with every insert.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    tiles: HashMap<Vec2<i64>, T>,
+    // the smallest and largest position holding a tile, both inclusive
+    bounds: Option<(Vec2<i64>, Vec2<i64>)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            tiles: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// The smallest and largest `x` and `y` holding a tile, both inclusive, none while empty.
+    pub fn bounds(&self) -> Option<(Vec2<i64>, Vec2<i64>)> {
+        self.bounds
+    }
+
+    /// Puts `tile` at `at`, returning the tile that was there.
+    pub fn insert(&mut self, at: Vec2<i64>, tile: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (at, at),
+            Some((min, max)) => (
+                Vec2::new(min.x.min(at.x), min.y.min(at.y)),
+                Vec2::new(max.x.max(at.x), max.y.max(at.y)),
+            ),
+        });
+        self.tiles.insert(at, tile)
+    }
+
+    pub fn contains(&self, at: Vec2<i64>) -> bool {
+        self.tiles.contains_key(&at)
+    }
+
+    pub fn get(&self, at: Vec2<i64>) -> Option<&T> {
+        self.tiles.get(&at)
+    }
+
+    pub fn get_mut(&mut self, at: Vec2<i64>) -> Option<&mut T> {
+        self.tiles.get_mut(&at)
+    }
+
+    /// Every tile with its position, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<i64>, &T)> {
+        self.tiles.iter().map(|(&at, tile)| (at, tile))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.tiles.values()
+    }
+
+    /// The tiles north, east, south and west of `at`, skipping empty positions.
+    pub fn neighbours(&self, at: Vec2<i64>) -> impl Iterator<Item = (Vec2<i64>, &T)> {
+        Direction::MOVE.into_iter().filter_map(move |direction| {
+            let next = at + direction;
+            Some((next, self.get(next)?))
+        })
+    }
+
+    /// The tiles from `min` to `max`, both inclusive like [`SparseGrid::bounds`], in no particular
+    /// order.
+    pub fn window(&self, min: Vec2<i64>, max: Vec2<i64>) -> impl Iterator<Item = (Vec2<i64>, &T)> {
+        self.iter().filter(move |(at, _)| {
+            (min.x..=max.x).contains(&at.x) && (min.y..=max.y).contains(&at.y)
+        })
+    }
+
+    /// A dense copy of the bounding box, with `empty` where there is no tile, and the position
+    /// of its top left corner.
+    pub fn to_grid(&self, empty: T) -> (Grid<T>, Vec2<i64>)
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return (Grid::new(0, 0, empty), Vec2::new(0, 0));
+        };
+
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+        let mut grid = Grid::new(width, height, empty);
+        for (at, tile) in self.iter() {
+            grid[Vec2::new(at.x - min.x, at.y - min.y)] = tile.clone();
+        }
+
+        (grid, min)
+    }
+
+    /// Draws the bounding box one line per row, with `empty` where there is no tile.
+    pub fn render(&self, empty: &T) -> String
+    where
+        T: fmt::Display,
+    {
+        let mut out = String::new();
+        if let Some((min, max)) = self.bounds {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    let tile = self.get(Vec2::new(x, y)).unwrap_or(empty);
+                    write!(out, "{tile}").unwrap();
+                }
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+// every tile of the grid, with its top left corner at the origin
+impl<T> From<Grid<T>> for SparseGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        let width = grid.width();
+        grid.into_tiles()
+            .into_iter()
+            .enumerate()
+            .map(|(i, tile)| (Vec2::new((i % width) as i64, (i / width) as i64), tile))
+            .collect()
+    }
+}
+
+impl<T> FromIterator<(Vec2<i64>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2<i64>, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Vec2<i64>, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Vec2<i64>, T)>>(&mut self, iter: I) {
+        for (at, tile) in iter {
+            self.insert(at, tile);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Grid, SparseGrid, Vec2};
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Vec2::new(2, -1), 'a');
+        grid.insert(Vec2::new(-3, 4), 'b');
+        assert_eq!(grid.insert(Vec2::new(2, -1), 'c'), Some('a'));
+
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some((Vec2::new(-3, -1), Vec2::new(2, 4))));
+        assert_eq!(grid.get(Vec2::new(2, -1)), Some(&'c'));
+        assert_eq!(
+            grid.window(Vec2::new(0, -5), Vec2::new(2, -1))
+                .collect::<Vec<_>>(),
+            [(Vec2::new(2, -1), &'c')]
+        );
+        assert_eq!(grid.window(Vec2::new(-2, -1), Vec2::new(2, 4)).count(), 1);
+        assert_eq!(
+            grid.neighbours(Vec2::new(2, 0)).collect::<Vec<_>>(),
+            [(Vec2::new(2, -1), &'c')]
+        );
+    }
+    #[test]
+    fn test_dense() {
+        let grid: SparseGrid<char> = [(Vec2::new(-1, 0), '#'), (Vec2::new(1, 1), 'S')]
+            .into_iter()
+            .collect();
+
+        assert_eq!(grid.render(&'.'), "#..\n..S\n");
+        let (dense, origin) = grid.to_grid('.');
+        assert_eq!(origin, Vec2::new(-1, 0));
+        assert_eq!(dense.to_string(), "#..\n..S\n");
+
+        let sparse = SparseGrid::from(Grid::parse("#.\n.S\n", |c| c).unwrap());
+        assert_eq!(sparse.len(), 4);
+        assert_eq!(sparse.get(Vec2::new(1, 1)), Some(&'S'));
+        assert_eq!(sparse.bounds(), Some((Vec2::new(0, 0), Vec2::new(1, 1))));
+        assert_eq!(SparseGrid::<char>::new().to_grid('.').0.width(), 0);
+    }
+}
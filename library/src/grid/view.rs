@@ -0,0 +1,181 @@
+use crate::grid::{Coord, Direction, Grid, Vec2};
+use std::fmt::{self, Write};
+
+impl<T> Grid<T> {
+    /// The grid with opposite edges joined, so leaving one side comes back in on the other.
+    pub fn wrapping(&self) -> Wrapping<'_, T> {
+        Wrapping { grid: self }
+    }
+
+    /// The grid repeated forever in every direction.
+    pub fn tiled(&self) -> Tiled<'_, T> {
+        Tiled { grid: self }
+    }
+
+    // the coordinate of `at` inside the grid and the copy of the grid it is in
+    fn split(&self, at: Vec2<i64>) -> (Coord, Vec2<i64>) {
+        let (width, height) = (self.width() as i64, self.height() as i64);
+        let coord = Coord::new(
+            at.x.rem_euclid(width) as usize,
+            at.y.rem_euclid(height) as usize,
+        );
+
+        (
+            coord,
+            Vec2::new(at.x.div_euclid(width), at.y.div_euclid(height)),
+        )
+    }
+}
+
+/// A [`Grid`] seen as a torus, see [`Grid::wrapping`].
+#[derive(Debug)]
+pub struct Wrapping<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+// derived impls would need `T: Copy`, the view only copies the reference
+impl<T> Clone for Wrapping<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Wrapping<'_, T> {}
+
+impl<'a, T> Wrapping<'a, T> {
+    /// The coordinate inside the grid that `at` wraps around to.
+    pub fn wrap(&self, at: Vec2<i64>) -> Coord {
+        self.grid.split(at).0
+    }
+
+    /// Where `by` moves `at` to, wrapping around the edges as many times as it needs.
+    pub fn step(&self, at: Coord, by: Vec2<i64>) -> Coord {
+        self.wrap(Vec2::new(at.x as i64 + by.x, at.y as i64 + by.y))
+    }
+
+    pub fn get(&self, at: Vec2<i64>) -> &'a T {
+        &self.grid[self.wrap(at)]
+    }
+
+    /// The neighbours north, east, south and west of `at`, wrapped onto the grid.
+    pub fn neighbours(&self, at: Coord) -> impl Iterator<Item = Coord> + 'a {
+        let view = *self;
+        Direction::MOVE
+            .into_iter()
+            .map(move |direction| view.step(at, direction * 1))
+    }
+}
+
+/// A [`Grid`] repeated forever, see [`Grid::tiled`]. The original is copy `(0, 0)` and the copy
+/// to its right is `(1, 0)`.
+#[derive(Debug)]
+pub struct Tiled<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<T> Clone for Tiled<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Tiled<'_, T> {}
+
+impl<'a, T> Tiled<'a, T> {
+    /// The coordinate in the original grid that `at` is a copy of, and which copy `at` is in.
+    pub fn locate(&self, at: Vec2<i64>) -> (Coord, Vec2<i64>) {
+        self.grid.split(at)
+    }
+
+    /// Which copy of the grid `at` is in.
+    pub fn copy_of(&self, at: Vec2<i64>) -> Vec2<i64> {
+        self.locate(at).1
+    }
+
+    /// The top left position of `copy`.
+    pub fn copy_origin(&self, copy: Vec2<i64>) -> Vec2<i64> {
+        Vec2::new(
+            copy.x * self.grid.width() as i64,
+            copy.y * self.grid.height() as i64,
+        )
+    }
+
+    pub fn get(&self, at: Vec2<i64>) -> &'a T {
+        &self.grid[self.locate(at).0]
+    }
+
+    /// The neighbours north, east, south and west of `at`, which are never off the map.
+    pub fn neighbours(&self, at: Vec2<i64>) -> impl Iterator<Item = Vec2<i64>> {
+        Direction::MOVE
+            .into_iter()
+            .map(move |direction| at + direction)
+    }
+
+    /// Draws the tiles from `min` up to but not including `max`, one line per row, with `tile`
+    /// choosing what each position looks like.
+    pub fn render<D: fmt::Display>(
+        &self,
+        min: Vec2<i64>,
+        max: Vec2<i64>,
+        mut tile: impl FnMut(Vec2<i64>, &T) -> D,
+    ) -> String {
+        let mut out = String::new();
+        for y in min.y..max.y {
+            for x in min.x..max.x {
+                let at = Vec2::new(x, y);
+                write!(out, "{}", tile(at, self.get(at))).unwrap();
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Coord, Grid, Vec2};
+
+    const INPUT: &str = "#..\n.S.\n";
+
+    #[test]
+    fn test_wrapping() {
+        let grid = Grid::parse(INPUT, |c| c).unwrap();
+        let torus = grid.wrapping();
+
+        assert_eq!(torus.wrap(Vec2::new(-1, -1)), Coord::new(2, 1));
+        assert_eq!(torus.wrap(Vec2::new(7, 4)), Coord::new(1, 0));
+        assert_eq!(
+            torus.step(Coord::new(2, 1), Vec2::new(-8, 3)),
+            Coord::new(0, 0)
+        );
+        assert_eq!(torus.get(Vec2::new(3, 2)), &'#');
+        assert_eq!(
+            torus.neighbours(Coord::new(0, 0)).collect::<Vec<_>>(),
+            [
+                Coord::new(0, 1),
+                Coord::new(1, 0),
+                Coord::new(0, 1),
+                Coord::new(2, 0)
+            ]
+        );
+    }
+    #[test]
+    fn test_tiled() {
+        let grid = Grid::parse(INPUT, |c| c).unwrap();
+        let tiled = grid.tiled();
+
+        assert_eq!(
+            tiled.locate(Vec2::new(-2, 5)),
+            (Coord::new(1, 1), Vec2::new(-1, 2))
+        );
+        assert_eq!(tiled.copy_of(Vec2::new(2, 1)), Vec2::new(0, 0));
+        assert_eq!(tiled.copy_origin(Vec2::new(-1, 2)), Vec2::new(-3, 4));
+        assert_eq!(tiled.get(Vec2::new(-2, -1)), &'S');
+        assert_eq!(
+            tiled.neighbours(Vec2::new(0, 0)).nth(3),
+            Some(Vec2::new(-1, 0))
+        );
+        assert_eq!(
+            tiled.render(Vec2::new(-1, 0), Vec2::new(4, 2), |_, &c| c),
+            ".#..#\n..S..\n"
+        );
+    }
+}
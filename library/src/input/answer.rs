@@ -0,0 +1,72 @@
+use crate::grid::{UVec2, Vec2};
+use std::fmt;
+
+/// Answer given as a position, displayed as `x,y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Coordinate<T>(pub T, pub T);
+
+impl<T: fmt::Display> fmt::Display for Coordinate<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
+impl<T> From<(T, T)> for Coordinate<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Coordinate(x, y)
+    }
+}
+
+impl<T> From<Vec2<T>> for Coordinate<T> {
+    fn from(v: Vec2<T>) -> Self {
+        Coordinate(v.x, v.y)
+    }
+}
+
+impl<T> From<UVec2<T>> for Coordinate<T> {
+    fn from(v: UVec2<T>) -> Self {
+        Coordinate(v.x, v.y)
+    }
+}
+
+/// Answer given as a list of values, displayed joined with commas like `4,6,3,5`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Joined<T>(pub Vec<T>);
+
+impl<T: fmt::Display> fmt::Display for Joined<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> FromIterator<T> for Joined<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Joined(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Vec2;
+    use crate::input::answer::{Coordinate, Joined};
+
+    #[test]
+    fn test_coordinate() {
+        assert_eq!(Coordinate(6, 1).to_string(), "6,1");
+        assert_eq!(Coordinate::from(Vec2 { x: -3, y: 4 }).to_string(), "-3,4");
+    }
+    #[test]
+    fn test_joined() {
+        assert_eq!(
+            [4, 6, 3, 5].into_iter().collect::<Joined<_>>().to_string(),
+            "4,6,3,5"
+        );
+        assert_eq!(Joined::<u8>(vec![]).to_string(), "");
+    }
+}
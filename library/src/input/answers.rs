@@ -0,0 +1,167 @@
+use crate::input::toml_answer::Answer;
+use crate::input::{AocError, InputType, Part};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const FILE_NAME: &str = "answers.toml";
+
+#[derive(Debug, Default, Deserialize)]
+struct PartAnswers {
+    part_1: Option<Answer>,
+    part_2: Option<Answer>,
+}
+
+/// Expected answers stored in `answers.toml` beside a day's input:
+///
+/// ```toml
+/// [example]
+/// part_1 = 142
+/// part_2 = 281
+///
+/// [input]
+/// part_1 = 54927
+///
+/// [inputs.alice]
+/// part_1 = 55130
+/// ```
+///
+/// The `inputs` tables hold the answers for other files, like the ones in an `inputs` directory,
+/// keyed by [`InputType::label`].
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    #[serde(default)]
+    example: PartAnswers,
+    #[serde(default)]
+    input: PartAnswers,
+    #[serde(default)]
+    inputs: BTreeMap<String, PartAnswers>,
+}
+
+impl Answers {
+    /// Loads the answers in `dir`, a missing file has no expected answers.
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        match fs::read_to_string(dir.join(FILE_NAME)) {
+            Ok(text) => Self::parse(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn parse(text: &str) -> io::Result<Self> {
+        toml::from_str(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn expected(&self, input_type: &InputType, part: Part) -> Option<String> {
+        let answers = match input_type {
+            InputType::Example => &self.example,
+            InputType::UserInput => &self.input,
+            InputType::File(_) => self.inputs.get(&input_type.label())?,
+            InputType::NamedExample(_) | InputType::Directory(_) | InputType::Stdin => return None,
+        };
+        let answer = match part {
+            Part::One => &answers.part_1,
+            Part::Two => &answers.part_2,
+        };
+        answer.as_ref().map(Answer::render)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass(String),
+    Fail { expected: String, actual: String },
+    Missing(String),
+    Error(AocError),
+}
+
+impl Verdict {
+    pub fn check(expected: Option<String>, actual: String) -> Self {
+        match expected {
+            None => Verdict::Missing(actual),
+            Some(expected) if expected == actual => Verdict::Pass(actual),
+            Some(expected) => Verdict::Fail { expected, actual },
+        }
+    }
+
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Error(_))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass(actual) => write!(f, "pass: {actual}"),
+            Verdict::Missing(actual) => write!(f, "missing: got {actual}, no answer recorded"),
+            Verdict::Error(e) => write!(f, "ERROR: {e}"),
+            Verdict::Fail { expected, actual } => {
+                writeln!(f, "FAIL")?;
+                writeln!(f, "  - expected: {expected}")?;
+                write!(f, "  + actual:   {actual}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::answers::{Answers, Verdict};
+    use crate::input::{InputType, Part};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_numbers_and_strings() {
+        let answers = Answers::parse(
+            "[example]\npart_1 = 142\npart_2 = \"4,6,3,5\"\n\n[input]\npart_1 = \"123456789012345678901\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers.expected(&InputType::Example, Part::One),
+            Some("142".to_string())
+        );
+        assert_eq!(
+            answers.expected(&InputType::Example, Part::Two),
+            Some("4,6,3,5".to_string())
+        );
+        assert_eq!(
+            answers.expected(&InputType::UserInput, Part::One),
+            Some("123456789012345678901".to_string())
+        );
+        assert_eq!(answers.expected(&InputType::UserInput, Part::Two), None);
+    }
+    #[test]
+    fn test_other_inputs() {
+        let answers =
+            Answers::parse("[input]\npart_1 = 1\n\n[inputs.alice]\npart_1 = 2\n").unwrap();
+
+        assert_eq!(
+            answers.expected(
+                &InputType::File(PathBuf::from("inputs/alice.txt")),
+                Part::One
+            ),
+            Some("2".to_string())
+        );
+        assert_eq!(
+            answers.expected(&InputType::File(PathBuf::from("inputs/bob.txt")), Part::One),
+            None
+        );
+        assert_eq!(answers.expected(&InputType::Stdin, Part::One), None);
+    }
+    #[test]
+    fn test_verdict() {
+        assert_eq!(
+            Verdict::check(Some("1".to_string()), "1".to_string()),
+            Verdict::Pass("1".to_string())
+        );
+        assert!(Verdict::check(Some("1".to_string()), "2".to_string()).is_fail());
+        assert_eq!(
+            Verdict::check(None, "2".to_string()),
+            Verdict::Missing("2".to_string())
+        );
+    }
+}
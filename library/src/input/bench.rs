@@ -0,0 +1,290 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// How many times [`Day::bench_in`](crate::input::Day::bench_in) runs each step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub runs: usize,
+    /// Runs before timing starts, their times are thrown away.
+    pub warm_up: usize,
+}
+
+impl BenchOptions {
+    pub fn new(runs: usize) -> Self {
+        Self { runs, warm_up: 0 }
+    }
+
+    pub fn with_warm_up(mut self, warm_up: usize) -> Self {
+        self.warm_up = warm_up;
+        self
+    }
+}
+
+/// Summary of the times of one benchmarked step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub max: Duration,
+    #[serde(with = "nanos")]
+    pub mean: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub p90: Duration,
+    #[serde(with = "nanos")]
+    pub p99: Duration,
+    #[serde(with = "nanos")]
+    pub std_dev: Duration,
+    /// Times outside 1.5 interquartile ranges of the middle half.
+    pub outliers: usize,
+}
+
+impl Stats {
+    pub fn from_times(mut times: Vec<Duration>) -> Option<Self> {
+        if times.is_empty() {
+            return None;
+        }
+        times.sort();
+        let runs = times.len();
+
+        let nanos: Vec<f64> = times.iter().map(|time| time.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        let median = if runs.is_multiple_of(2) {
+            (times[runs / 2 - 1] + times[runs / 2]) / 2
+        } else {
+            times[runs / 2]
+        };
+
+        let q1 = percentile(&times, 25.0).as_nanos() as f64;
+        let q3 = percentile(&times, 75.0).as_nanos() as f64;
+        let fence = 1.5 * (q3 - q1);
+        let outliers = nanos
+            .iter()
+            .filter(|&&x| x < q1 - fence || x > q3 + fence)
+            .count();
+
+        Some(Self {
+            runs,
+            min: times[0],
+            max: times[runs - 1],
+            mean: Duration::from_nanos(mean.round() as u64),
+            median,
+            p90: percentile(&times, 90.0),
+            p99: percentile(&times, 99.0),
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            outliers,
+        })
+    }
+}
+
+// nearest rank percentile of sorted times
+fn percentile(sorted: &[Duration], percent: f64) -> Duration {
+    let rank = (percent / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "median: {:?}, p90: {:?}, p99: {:?}, mean: {:?} ± {:?}, min: {:?}, max: {:?}, n: {}",
+            self.median, self.p90, self.p99, self.mean, self.std_dev, self.min, self.max, self.runs
+        )?;
+        if self.outliers > 0 {
+            write!(f, ", outliers: {}", self.outliers)?;
+        }
+        Ok(())
+    }
+}
+
+/// Times of one step, named like `parse` or `part_1`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub name: String,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+pub fn to_json(measurements: &[Measurement]) -> String {
+    serde_json::to_string_pretty(measurements).expect("measurements are always valid json")
+}
+
+/// Measurements as CSV with every duration in nanoseconds.
+pub fn to_csv(measurements: &[Measurement]) -> String {
+    let mut csv = String::from("name,runs,min,max,mean,median,p90,p99,std_dev,outliers\n");
+    for Measurement { name, stats } in measurements {
+        csv.push_str(&format!(
+            "{name},{},{},{},{},{},{},{},{},{}\n",
+            stats.runs,
+            stats.min.as_nanos(),
+            stats.max.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.median.as_nanos(),
+            stats.p90.as_nanos(),
+            stats.p99.as_nanos(),
+            stats.std_dev.as_nanos(),
+            stats.outliers
+        ));
+    }
+    csv
+}
+
+/// Measurements saved from an earlier benchmark, in the same JSON as [`to_json`].
+#[derive(Debug, Default)]
+pub struct Baseline {
+    stats: BTreeMap<String, Stats>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(text: &str) -> io::Result<Self> {
+        let measurements: Vec<Measurement> = serde_json::from_str(text)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Self::from(measurements))
+    }
+
+    pub fn save(path: &Path, measurements: &[Measurement]) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, to_json(measurements))
+    }
+
+    /// Compares the median against the baseline's, `threshold` is the fraction it may get slower by.
+    pub fn compare(&self, measurement: &Measurement, threshold: f64) -> Option<Change> {
+        let baseline = self.stats.get(&measurement.name)?.median;
+        let current = measurement.stats.median;
+        let ratio = current.as_nanos() as f64 / baseline.as_nanos().max(1) as f64;
+
+        Some(Change {
+            baseline,
+            current,
+            ratio,
+            regression: ratio > 1.0 + threshold,
+        })
+    }
+}
+
+impl From<Vec<Measurement>> for Baseline {
+    fn from(measurements: Vec<Measurement>) -> Self {
+        Self {
+            stats: measurements
+                .into_iter()
+                .map(|measurement| (measurement.name, measurement.stats))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Current median divided by the baseline median.
+    pub ratio: f64,
+    pub regression: bool,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:+.1}% against baseline {:?}",
+            (self.ratio - 1.0) * 100.0,
+            self.baseline
+        )?;
+        if self.regression {
+            write!(f, " REGRESSION")?;
+        }
+        Ok(())
+    }
+}
+
+// durations are stored as whole nanoseconds so the files are easy to read from other tools
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::bench::{to_csv, to_json, Baseline, Measurement, Stats};
+    use std::time::Duration;
+
+    fn micros(times: &[u64]) -> Vec<Duration> {
+        times.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_times(micros(&[5, 1, 4, 2, 3, 100])).unwrap();
+
+        assert_eq!(stats.runs, 6);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.max, Duration::from_micros(100));
+        assert_eq!(stats.median, Duration::from_nanos(3500));
+        assert_eq!(stats.p90, Duration::from_micros(100));
+        assert_eq!(stats.mean, Duration::from_nanos(19167));
+        assert_eq!(stats.outliers, 1);
+    }
+    #[test]
+    fn test_stats_without_spread() {
+        let stats = Stats::from_times(micros(&[7; 10])).unwrap();
+
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.p99, Duration::from_micros(7));
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(Stats::from_times(Vec::new()), None);
+    }
+    #[test]
+    fn test_baseline_round_trip() {
+        let measurement = Measurement {
+            name: "2024/day_1/part_1".to_string(),
+            stats: Stats::from_times(micros(&[10, 11, 12])).unwrap(),
+        };
+        let baseline = Baseline::parse(&to_json(std::slice::from_ref(&measurement))).unwrap();
+
+        let mut slower = measurement.clone();
+        slower.stats.median = Duration::from_micros(13);
+
+        assert!(!baseline.compare(&measurement, 0.05).unwrap().regression);
+        assert!(baseline.compare(&slower, 0.05).unwrap().regression);
+        assert!(!baseline.compare(&slower, 0.5).unwrap().regression);
+
+        slower.name = "2024/day_1/part_2".to_string();
+        assert_eq!(baseline.compare(&slower, 0.05), None);
+    }
+    #[test]
+    fn test_csv() {
+        let measurement = Measurement {
+            name: "parse".to_string(),
+            stats: Stats::from_times(micros(&[2])).unwrap(),
+        };
+
+        assert_eq!(
+            to_csv(&[measurement]),
+            "name,runs,min,max,mean,median,p90,p99,std_dev,outliers\nparse,1,2000,2000,2000,2000,2000,2000,0,0\n"
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use crate::input::{InputType, Params, Part};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const FILE_NAME: &str = ".answers.cache.toml";
+
+/// A hash of an input and its length, enough to tell whether it changed and how.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fingerprint {
+    hash: String,
+    len: usize,
+}
+
+impl Fingerprint {
+    pub fn of(input: &str) -> Self {
+        Self {
+            hash: hex(&Sha256::digest(input)),
+            len: input.len(),
+        }
+    }
+}
+
+/// How an input differs from the one its answers were last verified with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputChange {
+    Changed,
+    /// Shorter than the verified input, which usually means a download or copy was cut off.
+    Truncated {
+        verified: usize,
+        len: usize,
+    },
+}
+
+impl fmt::Display for InputChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputChange::Changed => write!(f, "changed since its answers were verified"),
+            InputChange::Truncated { verified, len } => write!(
+                f,
+                "may be truncated, it has {len} bytes but its answers were verified with {verified}"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// Answers keyed by [`AnswerCache::key`] and then `part_1` or `part_2`.
+    #[serde(default)]
+    answers: BTreeMap<String, BTreeMap<String, String>>,
+    /// Fingerprints of the inputs whose answers all passed, keyed by [`verified_key`].
+    #[serde(default)]
+    verified: BTreeMap<String, Fingerprint>,
+}
+
+/// Answers already found for a day, kept in `.answers.cache.toml` in its directory so slow days
+/// don't have to be solved again to see them. Each answer is keyed by a hash of the input, the
+/// parameters and the day's [`Day::VERSION`](crate::input::Day::VERSION), so changing any of them
+/// solves the day again.
+///
+/// The cache also remembers the input each day's answers were last verified with, to warn when an
+/// `input.txt` has changed or been cut short since.
+#[derive(Debug)]
+pub struct AnswerCache {
+    path: PathBuf,
+    params: String,
+    file: CacheFile,
+    changed: bool,
+}
+
+impl AnswerCache {
+    /// Loads the cache in `dir`. A missing or unreadable cache starts empty, it only costs solving
+    /// the days again.
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        let path = dir.join(FILE_NAME);
+        let file = match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).unwrap_or_default(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => CacheFile::default(),
+            Err(e) => return Err(e),
+        };
+        Ok(Self {
+            path,
+            params: String::new(),
+            file,
+            changed: false,
+        })
+    }
+
+    /// Keys the answers by the parameters given to the day as well.
+    pub fn with_params(mut self, params: &Params) -> Self {
+        self.params = params.to_string();
+        self
+    }
+
+    /// Examples are keyed apart from other inputs since days pick other constants for them.
+    pub fn key(&self, version: u32, input_type: &InputType, input: &str) -> String {
+        let digest = Sha256::new()
+            .chain_update(version.to_le_bytes())
+            .chain_update([input_type.is_example() as u8])
+            .chain_update((self.params.len() as u64).to_le_bytes())
+            .chain_update(&self.params)
+            .chain_update(input)
+            .finalize();
+        hex(&digest)
+    }
+
+    pub fn get(&self, key: &str, part: Part) -> Option<&str> {
+        let answer = self.file.answers.get(key)?.get(&part_key(part))?;
+        Some(answer)
+    }
+
+    pub fn insert(&mut self, key: &str, part: Part, answer: &str) {
+        let answers = self.file.answers.entry(key.to_string()).or_default();
+        if answers.get(&part_key(part)).map(String::as_str) != Some(answer) {
+            answers.insert(part_key(part), answer.to_string());
+            self.changed = true;
+        }
+    }
+
+    /// How `input` differs from the input of the same name whose answers were last verified.
+    pub fn check_input(&self, input_type: &InputType, input: &str) -> Option<InputChange> {
+        let verified = self.file.verified.get(&verified_key(input_type)?)?;
+        if verified.hash == Fingerprint::of(input).hash {
+            None
+        } else if input.len() < verified.len {
+            Some(InputChange::Truncated {
+                verified: verified.len,
+                len: input.len(),
+            })
+        } else {
+            Some(InputChange::Changed)
+        }
+    }
+
+    /// Remembers `input` as the one whose answers were verified. Stdin is not remembered, it is a
+    /// different input every time.
+    pub fn record_verified(&mut self, input_type: &InputType, input: &str) {
+        let Some(key) = verified_key(input_type) else {
+            return;
+        };
+        let fingerprint = Fingerprint::of(input);
+        if self.file.verified.get(&key) != Some(&fingerprint) {
+            self.file.verified.insert(key, fingerprint);
+            self.changed = true;
+        }
+    }
+
+    /// Writes the cache if anything was added to it.
+    pub fn save(&self) -> io::Result<()> {
+        if !self.changed {
+            return Ok(());
+        }
+        let text = toml::to_string(&self.file)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(&self.path, text)
+    }
+}
+
+// any file is keyed by its path, a label like `input` could be `input.txt` in another directory
+fn verified_key(input_type: &InputType) -> Option<String> {
+    match input_type {
+        InputType::Example | InputType::NamedExample(_) | InputType::UserInput => {
+            Some(input_type.label())
+        }
+        InputType::File(path) => Some(format!("file:{}", path.display())),
+        InputType::Directory(_) | InputType::Stdin => None,
+    }
+}
+
+fn part_key(part: Part) -> String {
+    format!("part_{}", part.number())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::cache::{AnswerCache, InputChange};
+    use crate::input::{InputType, Params, Part};
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_key() {
+        let dir = std::env::temp_dir();
+        let cache = AnswerCache::load(&dir.join("missing")).unwrap();
+        let key = cache.key(1, &InputType::UserInput, "1 2\n");
+
+        assert_eq!(key, cache.key(1, &InputType::UserInput, "1 2\n"));
+        assert_ne!(key, cache.key(2, &InputType::UserInput, "1 2\n"));
+        assert_ne!(key, cache.key(1, &InputType::Example, "1 2\n"));
+        assert_ne!(key, cache.key(1, &InputType::UserInput, "1 2"));
+        let params = Params::parse("steps = 6").unwrap();
+        let with_params = AnswerCache::load(&dir.join("missing"))
+            .unwrap()
+            .with_params(&params);
+        assert_ne!(key, with_params.key(1, &InputType::UserInput, "1 2\n"));
+    }
+    #[test]
+    fn test_save() {
+        let dir = std::env::temp_dir().join(format!("aoc_cache_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut cache = AnswerCache::load(&dir).unwrap();
+        cache.insert("abc", Part::Two, "42");
+        cache.record_verified(&InputType::UserInput, "1\n2\n3\n");
+        cache.save().unwrap();
+        let loaded = AnswerCache::load(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded.get("abc", Part::Two), Some("42"));
+        assert_eq!(loaded.get("abc", Part::One), None);
+        let input = InputType::UserInput;
+        assert_eq!(loaded.check_input(&input, "1\n2\n3\n"), None);
+        assert_eq!(
+            loaded.check_input(&input, "1\n2\n4\n"),
+            Some(InputChange::Changed)
+        );
+        assert_eq!(
+            loaded.check_input(&input, "1\n2"),
+            Some(InputChange::Truncated {
+                verified: 6,
+                len: 3
+            })
+        );
+        assert_eq!(loaded.check_input(&InputType::Example, "1\n"), None);
+    }
+    #[test]
+    fn test_verified_keys() {
+        let dir = std::env::temp_dir().join("missing");
+        let mut cache = AnswerCache::load(&dir).unwrap();
+        cache.record_verified(&InputType::UserInput, "1\n2\n3\n");
+        cache.record_verified(&InputType::Stdin, "4\n");
+
+        let other = InputType::File(PathBuf::from("inputs/input.txt"));
+        assert_eq!(cache.check_input(&other, "5\n"), None);
+        assert_eq!(cache.check_input(&InputType::Stdin, "5\n"), None);
+        cache.record_verified(&other, "5\n");
+        assert_eq!(cache.check_input(&InputType::UserInput, "1\n2\n3\n"), None);
+        assert_eq!(cache.check_input(&other, "6\n"), Some(InputChange::Changed));
+    }
+}
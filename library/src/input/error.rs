@@ -0,0 +1,93 @@
+use std::fmt;
+
+/// Error from a day that could not handle its input, pointing at where in the input it went wrong.
+/// Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Moves the error down by `lines`, for errors from a section that starts partway through the input.
+    pub fn after_lines(mut self, lines: usize) -> Self {
+        self.line = self.line.map(|line| line + lines);
+        self
+    }
+
+    /// Parses every line of `input`, adding the line number to the first error.
+    pub fn parse_lines<T>(
+        input: &str,
+        parse: impl Fn(&str) -> Result<T, AocError>,
+    ) -> Result<Vec<T>, AocError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                parse(line).map_err(|e| match e.line {
+                    Some(_) => e,
+                    None => e.at_line(i + 1),
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            (None, Some(column)) => write!(f, "column {column}: ")?,
+            (None, None) => (),
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::error::AocError;
+
+    #[test]
+    fn test_parse_lines_adds_line_number() {
+        let result = AocError::parse_lines("1\n2\nx\n4", |line| {
+            line.parse::<u32>()
+                .map_err(|_| AocError::new(format!("'{line}' is not a number")).at_column(1))
+        });
+
+        let error = result.unwrap_err();
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.to_string(), "line 3, column 1: 'x' is not a number");
+    }
+    #[test]
+    fn test_parse_lines() {
+        let result = AocError::parse_lines("1\n2", |line| {
+            line.parse::<u32>()
+                .map_err(|e| AocError::new(e.to_string()))
+        });
+
+        assert_eq!(result, Ok(vec![1, 2]));
+    }
+}
@@ -0,0 +1,18 @@
+use crate::input::{Day, InputType, Params, Part};
+
+/// Runs one answer from a day's `examples.toml`, called by the tests [`examples!`](crate::input::examples!) generates.
+/// `params` is the entry's parameter table as TOML, applied over the day's example constants.
+pub fn check<D: Day>(mut day: D, input: &str, part: Part, params: &str, expected: &str) {
+    Params::parse(params)
+        .and_then(|params| day.setup(&InputType::Example, &params))
+        .unwrap_or_else(|e| panic!("{e}"));
+
+    let parsed = day
+        .parse(input)
+        .unwrap_or_else(|e| panic!("Parse error: {e}"));
+    let actual = day
+        .solve(part, &parsed)
+        .unwrap_or_else(|e| panic!("Part {} error: {e}", part.number()));
+
+    assert_eq!(actual, expected, "Part {} answer", part.number());
+}
@@ -0,0 +1,117 @@
+use crate::input::store;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where a day's input comes from. Relative paths start from the day's directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputType {
+    /// `example.txt`
+    Example,
+    /// Another example file, like `example2.txt`.
+    NamedExample(String),
+    /// `input.txt`, or `input.txt.enc` decrypted with the local key, see [`store`](crate::input::store).
+    UserInput,
+    /// Any file, like a colleague's input.
+    File(PathBuf),
+    /// Every `.txt` file in a directory, like `inputs/<user>.txt`, see [`InputType::expand`].
+    Directory(PathBuf),
+    Stdin,
+}
+
+impl InputType {
+    /// Examples are run with the day's example parameters, see [`Day::select_params`](crate::input::Day::select_params).
+    pub fn is_example(&self) -> bool {
+        matches!(self, Self::Example | Self::NamedExample(_))
+    }
+
+    /// Short name printed before the results of one of several inputs, and the key of its answers in
+    /// `answers.toml`. Files are named without their directory or extension.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Example => "example".to_string(),
+            Self::NamedExample(name) => file_stem(Path::new(name)),
+            Self::UserInput => "input".to_string(),
+            Self::File(path) => file_stem(path),
+            Self::Directory(path) => path.display().to_string(),
+            Self::Stdin => "stdin".to_string(),
+        }
+    }
+
+    /// The inputs to run, one per file sorted by name for a directory and just this one otherwise.
+    pub fn expand(&self, dir: &Path) -> io::Result<Vec<InputType>> {
+        let Self::Directory(path) = self else {
+            return Ok(vec![self.clone()]);
+        };
+
+        let mut files = Vec::new();
+        for entry in fs::read_dir(dir.join(path))? {
+            let file = entry?.path();
+            if file.extension().is_some_and(|extension| extension == "txt") {
+                files.push(file);
+            }
+        }
+        files.sort();
+
+        Ok(files.into_iter().map(Self::File).collect())
+    }
+
+    pub fn read(&self, dir: &Path) -> io::Result<String> {
+        match self {
+            Self::Example => fs::read_to_string(dir.join("example.txt")),
+            Self::NamedExample(name) => fs::read_to_string(dir.join(name)),
+            Self::UserInput => store::read_input(dir),
+            Self::File(path) => fs::read_to_string(dir.join(path)),
+            Self::Directory(path) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} holds several inputs, expand it first", path.display()),
+            )),
+            Self::Stdin => io::read_to_string(io::stdin()),
+        }
+    }
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::InputType;
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_expand_directory() {
+        let dir = std::env::temp_dir().join(format!("aoc_inputs_{}", std::process::id()));
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        for file in ["bob.txt", "alice.txt", "notes.md"] {
+            fs::write(dir.join("inputs").join(file), file).unwrap();
+        }
+
+        let inputs = InputType::Directory(PathBuf::from("inputs"))
+            .expand(&dir)
+            .unwrap();
+        let labels: Vec<String> = inputs.iter().map(InputType::label).collect();
+        let alice = inputs[0].read(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(labels, ["alice", "bob"]);
+        assert_eq!(alice.unwrap(), "alice.txt");
+    }
+    #[test]
+    fn test_expand_single() {
+        let input = InputType::NamedExample("example2.txt".to_string());
+
+        assert_eq!(
+            input.expand(&PathBuf::new()).unwrap(),
+            std::slice::from_ref(&input)
+        );
+        assert_eq!(input.label(), "example2");
+        assert!(input.is_example());
+        assert!(!InputType::File(PathBuf::from("example.txt")).is_example());
+    }
+}
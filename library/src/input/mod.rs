@@ -1,80 +1,515 @@
-use num::Integer;
-use std::fs;
-use std::time::{Duration, Instant};
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+mod error;
+pub mod examples;
+mod input_type;
+mod params;
+pub mod report;
+pub mod store;
+mod toml_answer;
 
-pub enum InputType {
-    Example,
-    UserInput,
+pub use answer::{Coordinate, Joined};
+use answers::{Answers, Verdict};
+use bench::{BenchOptions, Measurement, Stats};
+use cache::AnswerCache;
+pub use error::AocError;
+pub use input_type::InputType;
+pub use library_macros::examples;
+pub use params::Params;
+use report::{PartReport, Report};
+use std::fmt::Display;
+use std::path::Path;
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
 }
-impl InputType {
-    fn to_file_name(&self) -> String {
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub const fn number(&self) -> u8 {
         match self {
-            Self::Example => "example.txt".to_string(),
-            Self::UserInput => "input.txt".to_string(),
+            Part::One => 1,
+            Part::Two => 2,
         }
     }
 }
 
-pub trait Day<T: Integer + std::fmt::Display> {
-    fn part_1(&self, input: &str) -> T;
-    fn part_2(&mut self, input: &str) -> T;
+/// The number of puzzles in `year`, the calendar was cut down to 12 days from 2025.
+pub fn days_in(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// A day's puzzle. The input is parsed once by `parse` and the result is handed to both parts.
+/// Each part returns an [`AocError`] for an input it can't solve, so the runner reports a malformed
+/// input instead of panicking. `part_1` and `part_2` unwrap them for callers that want the answer.
+///
+/// Each part has its own answer type, anything that can be displayed works: integers, `u128`,
+/// `String`, or [`Coordinate`] and [`Joined`] for answers written as comma separated values.
+pub trait Day {
+    type Parsed;
+    type Part1: Display;
+    type Part2: Display;
+
+    /// Version of the implementation, part of the key of the day's cached answers. Bump it when a
+    /// change can give different answers so the ones cached before aren't printed again.
+    const VERSION: u32 = 1;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, AocError>;
+
+    /// Picks the constants for `input_type`, for puzzles whose examples use smaller ones like a grid size
+    /// or a number of steps. The day's `DAY` constant holds the ones for the real input.
+    fn select_params(&mut self, _input_type: &InputType) {}
+
+    /// Sets the day's constants from `params`, taking the ones it knows with [`Params::take`].
+    /// Days without constants take nothing, so any parameter given to them is reported as unknown.
+    fn configure(&mut self, _params: &mut Params) -> Result<(), AocError> {
+        Ok(())
+    }
+
+    /// Picks the constants for `input_type`, then overrides them with `params` from the command line
+    /// or `examples.toml`.
+    fn setup(&mut self, input_type: &InputType, params: &Params) -> Result<(), AocError> {
+        self.select_params(input_type);
+        let mut params = params.clone();
+        self.configure(&mut params)?;
+        params.finish()
+    }
+
+    fn try_part_1(&self, parsed: &Self::Parsed) -> Result<Self::Part1, AocError>;
+    fn try_part_2(&mut self, parsed: &Self::Parsed) -> Result<Self::Part2, AocError>;
+
+    /// Solves part 1, panicking on an error.
+    fn part_1(&self, parsed: &Self::Parsed) -> Self::Part1 {
+        self.try_part_1(parsed).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Solves part 2, panicking on an error.
+    fn part_2(&mut self, parsed: &Self::Parsed) -> Self::Part2 {
+        self.try_part_2(parsed).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Parses `input` and solves part 1, panicking on an error.
+    fn answer_1(&self, input: &str) -> Self::Part1 {
+        let parsed = self.parse(input).unwrap_or_else(|e| panic!("{e}"));
+        self.part_1(&parsed)
+    }
+    /// Parses `input` and solves part 2, panicking on an error.
+    fn answer_2(&mut self, input: &str) -> Self::Part2 {
+        let parsed = self.parse(input).unwrap_or_else(|e| panic!("{e}"));
+        self.part_2(&parsed)
+    }
+
+    /// Solves one part, rendering the answer as it is printed and recorded in `answers.toml`.
+    fn solve(&mut self, part: Part, parsed: &Self::Parsed) -> Result<String, AocError> {
+        match part {
+            Part::One => self.try_part_1(parsed).map(|answer| answer.to_string()),
+            Part::Two => self.try_part_2(parsed).map(|answer| answer.to_string()),
+        }
+    }
 
     fn run(&mut self, input_type: InputType) -> std::io::Result<()> {
-        let input = fs::read_to_string(input_type.to_file_name())?;
+        self.select_params(&input_type);
+        self.run_in(Path::new("."), input_type, &Part::ALL)
+    }
 
-        let start: Instant = Instant::now();
-        let part_1_answer = self.part_1(&input);
-        let duration = start.elapsed();
+    /// Prints the answers for every input `input_type` stands for, labelled when there are several.
+    fn run_in(&mut self, dir: &Path, input_type: InputType, parts: &[Part]) -> std::io::Result<()> {
+        self.run_cached_in(dir, input_type, parts, None)
+    }
 
-        println!("Part 1 answer: {}, time: {:?}", part_1_answer, duration);
+    /// Like [`Day::run_in`], printing the answers found in `cache` without parsing or solving
+    /// anything and adding the new ones to it. Warns when an input changed since it was verified.
+    fn run_cached_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+        mut cache: Option<&mut AnswerCache>,
+    ) -> std::io::Result<()> {
+        let inputs = input_type.expand(dir)?;
 
-        let start: Instant = Instant::now();
-        let part_2_answer = self.part_2(&input);
-        let duration = start.elapsed();
-        println!("Part 2 answer: {}, time: {:?}", part_2_answer, duration);
+        'inputs: for input_type in &inputs {
+            if inputs.len() > 1 {
+                println!("Input {}", input_type.label());
+            }
+            let input = input_type.read(dir)?;
+
+            let key = cache.as_deref().map(|cache| {
+                if let Some(change) = cache.check_input(input_type, &input) {
+                    println!("Warning: {} {change}", input_type.label());
+                }
+                cache.key(Self::VERSION, input_type, &input)
+            });
+
+            let mut parsed = None;
+            for &part in parts {
+                let cached = cache
+                    .as_deref()
+                    .zip(key.as_deref())
+                    .and_then(|(cache, key)| cache.get(key, part));
+                if let Some(answer) = cached {
+                    println!("Part {} answer: {answer} (cached)", part.number());
+                    continue;
+                }
+
+                if parsed.is_none() {
+                    let start: Instant = Instant::now();
+                    let result = self.parse(&input);
+                    let duration = start.elapsed();
+
+                    match result {
+                        Ok(result) => parsed = Some(result),
+                        Err(e) => {
+                            println!("Parse error: {e}");
+                            continue 'inputs;
+                        }
+                    }
+                    println!("Parse time: {duration:?}");
+                }
+                let parsed = parsed.as_ref().expect("the input was parsed above");
+
+                let start: Instant = Instant::now();
+                let answer = self.solve(part, parsed);
+                let duration = start.elapsed();
+
+                match answer {
+                    Ok(answer) => {
+                        println!(
+                            "Part {} answer: {}, time: {:?}",
+                            part.number(),
+                            answer,
+                            duration
+                        );
+                        if let (Some(cache), Some(key)) = (cache.as_deref_mut(), &key) {
+                            cache.insert(key, part, &answer);
+                        }
+                    }
+                    Err(e) => println!("Part {} error: {e}", part.number()),
+                }
+            }
+        }
 
         Ok(())
     }
 
     fn run_n(&mut self, input_type: InputType, n: usize) -> std::io::Result<()> {
-        let input = fs::read_to_string(input_type.to_file_name())?;
+        self.select_params(&input_type);
+        let measurements =
+            self.bench_in(Path::new("."), input_type, &Part::ALL, BenchOptions::new(n))?;
+        for measurement in measurements {
+            println!("{} {}", measurement.name, measurement.stats);
+        }
+        Ok(())
+    }
 
-        let mut times = Vec::new();
+    /// Times parsing and each part separately, after `options.warm_up` untimed runs.
+    /// A step that fails is reported and left out of the measurements. With several inputs
+    /// the steps are named after the input, like `alice/part_1`.
+    fn bench_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+        options: BenchOptions,
+    ) -> std::io::Result<Vec<Measurement>> {
+        let inputs = input_type.expand(dir)?;
+        let mut measurements = Vec::new();
 
-        for _ in 0..n {
-            let start: Instant = Instant::now();
-            self.part_1(&input);
-            let duration = start.elapsed();
+        'inputs: for input_type in &inputs {
+            let prefix = match inputs.len() {
+                1 => String::new(),
+                _ => format!("{}/", input_type.label()),
+            };
+            let input = input_type.read(dir)?;
+
+            let mut times = Vec::new();
+            let mut parsed = None;
+            for i in 0..options.warm_up + options.runs {
+                let start: Instant = Instant::now();
+                let result = self.parse(&input);
+                let duration = start.elapsed();
+
+                match result {
+                    Ok(result) => parsed = Some(result),
+                    Err(e) => {
+                        eprintln!("{prefix}Parse error: {e}");
+                        continue 'inputs;
+                    }
+                }
+                if i >= options.warm_up {
+                    times.push(duration);
+                }
+            }
+            let Some(parsed) = parsed else {
+                continue;
+            };
+            measurements.extend(Stats::from_times(times).map(|stats| Measurement {
+                name: format!("{prefix}parse"),
+                stats,
+            }));
+
+            'parts: for &part in parts {
+                let mut times = Vec::new();
+
+                for i in 0..options.warm_up + options.runs {
+                    let start: Instant = Instant::now();
+                    let answer = self.solve(part, &parsed);
+                    let duration = start.elapsed();
+
+                    if let Err(e) = answer {
+                        eprintln!("{prefix}Part {} error: {e}", part.number());
+                        continue 'parts;
+                    }
+                    if i >= options.warm_up {
+                        times.push(duration);
+                    }
+                }
 
-            times.push(duration);
+                measurements.extend(Stats::from_times(times).map(|stats| Measurement {
+                    name: format!("{prefix}part_{}", part.number()),
+                    stats,
+                }));
+            }
         }
-        times.sort();
 
-        println!(
-            "Part 1 time: {:?}, {:?}, {:?}, n: {n}",
-            times[0],
-            times.iter().sum::<Duration>() / n.try_into().unwrap(),
-            times[n - 1],
-        );
+        Ok(measurements)
+    }
+
+    fn verify(&mut self, input_type: InputType) -> std::io::Result<bool> {
+        self.select_params(&input_type);
+        self.verify_in(Path::new("."), input_type, &Part::ALL)
+    }
+
+    /// Runs the parts and compares each answer with the one recorded in `answers.toml`.
+    /// Returns false if any part gave a different answer or an error, missing answers are only reported.
+    fn verify_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+    ) -> std::io::Result<bool> {
+        let inputs = input_type.expand(dir)?;
+
+        let mut passed = true;
+        for input_type in &inputs {
+            if inputs.len() > 1 {
+                println!("Input {}", input_type.label());
+            }
+            // warned before solving, a cut off input may well panic
+            let input = input_type.read(dir)?;
+            if let Some(change) = AnswerCache::load(dir)?.check_input(input_type, &input) {
+                println!("Warning: {} {change}", input_type.label());
+            }
+            let report = self.report_with_input(dir, input_type.clone(), &input, parts)?;
+            for part in &report.parts {
+                println!("Part {} {}", part.part.number(), part.verdict);
+            }
+            passed &= report.passed();
+        }
+
+        Ok(passed)
+    }
+
+    /// Parses and solves the parts once without printing anything, checking the answers against
+    /// `answers.toml`. `input_type` has to be a single input, not a directory. An input whose parts
+    /// all pass is remembered in the day's [`AnswerCache`] as the verified one.
+    fn report_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+    ) -> std::io::Result<Report> {
+        let input = input_type.read(dir)?;
+        self.report_with_input(dir, input_type, &input, parts)
+    }
 
-        let mut times = Vec::new();
+    /// [`Day::report_in`] for an input already read, which stdin can only be once.
+    fn report_with_input(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        input: &str,
+        parts: &[Part],
+    ) -> std::io::Result<Report> {
+        let answers = Answers::load(dir)?;
 
-        for _ in 0..n {
+        let start: Instant = Instant::now();
+        let parsed = self.parse(input);
+        let parse_time = start.elapsed();
+
+        let mut reports = Vec::new();
+        for &part in parts {
             let start: Instant = Instant::now();
-            self.part_2(&input);
-            let duration = start.elapsed();
+            let answer = parsed
+                .as_ref()
+                .map_err(Clone::clone)
+                .and_then(|parsed| self.solve(part, parsed));
+            let time = start.elapsed();
 
-            times.push(duration);
+            let verdict = match answer {
+                Ok(actual) => Verdict::check(answers.expected(&input_type, part), actual),
+                Err(e) => Verdict::Error(e),
+            };
+            reports.push(PartReport {
+                part,
+                verdict,
+                time,
+            });
         }
-        times.sort();
 
-        println!(
-            "Part 2 time: {:?}, {:?}, {:?}, n: {n}",
-            times[0],
-            times.iter().sum::<Duration>() / n.try_into().unwrap(),
-            times[n - 1],
-        );
+        if !reports.is_empty()
+            && reports
+                .iter()
+                .all(|report| matches!(report.verdict, Verdict::Pass(_)))
+        {
+            let mut cache = AnswerCache::load(dir)?;
+            cache.record_verified(&input_type, input);
+            cache.save()?;
+        }
 
-        Ok(())
+        Ok(Report {
+            parse_time: parsed.is_ok().then_some(parse_time),
+            parts: reports,
+        })
+    }
+
+    fn into_solution(self) -> Box<dyn Solution>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(DaySolution(self))
+    }
+}
+
+/// Object safe view of a [`Day`] so days with different answer types can be stored together.
+pub trait Solution {
+    fn setup(&mut self, input_type: &InputType, params: &Params) -> Result<(), AocError>;
+    fn run_in(&mut self, dir: &Path, input_type: InputType, parts: &[Part]) -> std::io::Result<()>;
+    fn run_cached_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+        cache: &mut AnswerCache,
+    ) -> std::io::Result<()>;
+    fn bench_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+        options: BenchOptions,
+    ) -> std::io::Result<Vec<Measurement>>;
+    fn verify_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+    ) -> std::io::Result<bool>;
+    fn report_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+    ) -> std::io::Result<Report>;
+}
+
+struct DaySolution<D>(D);
+
+impl<D: Day> Solution for DaySolution<D> {
+    fn setup(&mut self, input_type: &InputType, params: &Params) -> Result<(), AocError> {
+        self.0.setup(input_type, params)
+    }
+    fn run_in(&mut self, dir: &Path, input_type: InputType, parts: &[Part]) -> std::io::Result<()> {
+        self.0.run_in(dir, input_type, parts)
+    }
+    fn run_cached_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+        cache: &mut AnswerCache,
+    ) -> std::io::Result<()> {
+        self.0.run_cached_in(dir, input_type, parts, Some(cache))
+    }
+    fn bench_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+        options: BenchOptions,
+    ) -> std::io::Result<Vec<Measurement>> {
+        self.0.bench_in(dir, input_type, parts, options)
+    }
+    fn verify_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+    ) -> std::io::Result<bool> {
+        self.0.verify_in(dir, input_type, parts)
+    }
+    fn report_in(
+        &mut self,
+        dir: &Path,
+        input_type: InputType,
+        parts: &[Part],
+    ) -> std::io::Result<Report> {
+        self.0.report_in(dir, input_type, parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::answers::Verdict;
+    use crate::input::{days_in, AocError, Day, InputType, Part};
+
+    struct Lines;
+    impl Day for Lines {
+        type Parsed = Vec<u32>;
+        type Part1 = usize;
+        type Part2 = u32;
+
+        fn parse(&self, input: &str) -> Result<Vec<u32>, AocError> {
+            AocError::parse_lines(input, |line| {
+                line.parse().map_err(|_| AocError::new("not a number"))
+            })
+        }
+        fn try_part_1(&self, numbers: &Vec<u32>) -> Result<usize, AocError> {
+            Ok(numbers.len())
+        }
+        fn try_part_2(&mut self, numbers: &Vec<u32>) -> Result<u32, AocError> {
+            Ok(numbers.iter().sum())
+        }
+    }
+
+    #[test]
+    fn test_report_with_input() {
+        let dir = std::env::temp_dir().join("missing");
+        let report = Lines
+            .report_with_input(&dir, InputType::Stdin, "1\n2\n3\n", &Part::ALL)
+            .unwrap();
+
+        let verdicts: Vec<&Verdict> = report.parts.iter().map(|part| &part.verdict).collect();
+        assert_eq!(
+            verdicts,
+            [
+                &Verdict::Missing("3".to_string()),
+                &Verdict::Missing("6".to_string())
+            ]
+        );
+        assert!(report.parse_time.is_some());
+    }
+    #[test]
+    fn test_days_in() {
+        assert_eq!(days_in(2024), 25);
+        assert_eq!(days_in(2025), 12);
     }
 }
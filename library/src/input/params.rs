@@ -0,0 +1,99 @@
+use crate::input::AocError;
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+/// Named values for a day's constants, like a number of steps, that differ between the examples
+/// and the real input. Written as a TOML table: `steps = 6` or `range = [7.0, 27.0]`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    values: toml::Table,
+}
+
+impl Params {
+    pub fn parse(text: &str) -> Result<Self, AocError> {
+        let values = toml::from_str(text).map_err(|e| {
+            let message = match e.message() {
+                "" => "expected a value".to_string(),
+                message => message.replace('\n', ", "),
+            };
+            AocError::new(format!("invalid parameters: {message}"))
+        })?;
+        Ok(Self { values })
+    }
+
+    /// Parses `name=value` pairs like the ones given with `--param`, each value written as TOML.
+    pub fn from_assignments<S: AsRef<str>>(assignments: &[S]) -> Result<Self, AocError> {
+        let mut params = Self::default();
+        for assignment in assignments {
+            let assignment = assignment.as_ref();
+            let parsed = Self::parse(assignment)
+                .map_err(|e| AocError::new(format!("{assignment}: {}", e.message)))?;
+            params.values.extend(parsed.values);
+        }
+        Ok(params)
+    }
+
+    /// Removes the parameter called `name`, `None` if it was not given.
+    pub fn take<T: DeserializeOwned>(&mut self, name: &str) -> Result<Option<T>, AocError> {
+        let Some(value) = self.values.remove(name) else {
+            return Ok(None);
+        };
+        value
+            .try_into()
+            .map(Some)
+            .map_err(|e| AocError::new(format!("parameter {name}: {}", e.message())))
+    }
+
+    /// Fails if any parameter was not taken, so a misspelt name is not silently ignored.
+    pub fn finish(self) -> Result<(), AocError> {
+        match self.values.keys().next() {
+            Some(name) => Err(AocError::new(format!("unknown parameter {name}"))),
+            None => Ok(()),
+        }
+    }
+}
+
+// written back as the TOML table they were parsed from
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::params::Params;
+
+    #[test]
+    fn test_take() {
+        let mut params = Params::parse("steps = 6\nrange = [7.0, 27.0]").unwrap();
+
+        assert_eq!(params.take::<u32>("steps"), Ok(Some(6)));
+        assert_eq!(params.take::<u32>("steps"), Ok(None));
+        assert_eq!(params.take::<(f64, f64)>("range"), Ok(Some((7.0, 27.0))));
+        assert_eq!(params.finish(), Ok(()));
+    }
+    #[test]
+    fn test_from_assignments() {
+        let mut params = Params::from_assignments(&["width=11", "height = 7"]).unwrap();
+
+        assert_eq!(params.take::<usize>("width"), Ok(Some(11)));
+        assert_eq!(params.take::<usize>("height"), Ok(Some(7)));
+        assert_eq!(
+            Params::from_assignments(&["steps="])
+                .unwrap_err()
+                .to_string(),
+            "steps=: invalid parameters: expected a value"
+        );
+    }
+    #[test]
+    fn test_errors() {
+        let mut params = Params::parse("steps = -1\nstesp = 6").unwrap();
+
+        assert!(params.take::<u32>("steps").is_err());
+        assert_eq!(
+            params.finish().unwrap_err().to_string(),
+            "unknown parameter stesp"
+        );
+    }
+}
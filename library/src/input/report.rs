@@ -0,0 +1,39 @@
+use crate::input::answers::Verdict;
+use crate::input::Part;
+use std::time::Duration;
+
+/// Outcome of parsing an input once and solving the parts, from [`Day::report_in`](crate::input::Day::report_in).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// `None` when parsing failed, every part is then an error.
+    pub parse_time: Option<Duration>,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartReport {
+    pub part: Part,
+    pub verdict: Verdict,
+    pub time: Duration,
+}
+
+impl Report {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time.unwrap_or_default()
+            + self.parts.iter().map(|part| part.time).sum::<Duration>()
+    }
+
+    /// True if no part gave a wrong answer or an error.
+    pub fn passed(&self) -> bool {
+        self.parts.iter().all(|part| !part.verdict.is_fail())
+    }
+
+    /// True if every part gave its recorded answer, a part without one isn't verified.
+    pub fn verified(&self) -> bool {
+        !self.parts.is_empty()
+            && self
+                .parts
+                .iter()
+                .all(|part| matches!(part.verdict, Verdict::Pass(_)))
+    }
+}
@@ -0,0 +1,243 @@
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The plain input, which git ignores.
+pub const INPUT_FILE: &str = "input.txt";
+/// The input encrypted with the local key, which can be committed.
+pub const ENCRYPTED_FILE: &str = "input.txt.enc";
+/// Variable naming the key file, instead of `aoc/input.key` in the user's config directory.
+pub const KEY_FILE_VAR: &str = "AOC_KEY_FILE";
+
+// identifies the format of an encrypted input, followed by the nonce and then the ciphertext
+const MAGIC: &[u8] = b"AOC1";
+const NONCE_LEN: usize = 12;
+
+/// The symmetric key that inputs are stored with, kept as 64 hex digits in a file outside the repository.
+pub struct InputKey {
+    key: [u8; 32],
+}
+
+impl InputKey {
+    /// Where the key is kept: the file named by `AOC_KEY_FILE`, or `aoc/input.key` in the user's
+    /// config directory, like `~/.config/aoc/input.key`.
+    pub fn path() -> io::Result<PathBuf> {
+        if let Some(path) = std::env::var_os(KEY_FILE_VAR) {
+            return Ok(PathBuf::from(path));
+        }
+        dirs::config_dir()
+            .map(|dir| dir.join("aoc").join("input.key"))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no config directory for the key, set {KEY_FILE_VAR}"),
+                )
+            })
+    }
+
+    pub fn load() -> io::Result<Self> {
+        let path = Self::path()?;
+        let text = fs::read_to_string(&path).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("can't read the key {}: {e}", path.display()),
+            )
+        })?;
+        Self::from_hex(&text)
+    }
+
+    /// Makes a new random key and saves it, an existing key is never replaced.
+    pub fn generate() -> io::Result<(Self, PathBuf)> {
+        let path = Self::path()?;
+        if path.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already holds a key", path.display()),
+            ));
+        }
+        let key = Self {
+            key: Aes256Gcm::generate_key(OsRng).into(),
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, key.to_hex() + "\n")?;
+        Ok((key, path))
+    }
+
+    pub fn from_hex(text: &str) -> io::Result<Self> {
+        let text = text.trim();
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the key should be 64 hex digits",
+            )
+        };
+        if text.len() != 64 || !text.is_ascii() {
+            return Err(invalid());
+        }
+
+        let mut key = [0; 32];
+        for (byte, digits) in key.iter_mut().zip(text.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).map_err(|_| invalid())?;
+            *byte = u8::from_str_radix(digits, 16).map_err(|_| invalid())?;
+        }
+        Ok(Self { key })
+    }
+
+    fn to_hex(&self) -> String {
+        self.key.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.key))
+    }
+
+    /// The nonce comes from the key and the input so encrypting an unchanged input gives the same
+    /// file again, and committing every input again doesn't show up as a change.
+    pub fn encrypt(&self, input: &str) -> Vec<u8> {
+        let digest = Sha256::new()
+            .chain_update(self.key)
+            .chain_update(input)
+            .finalize();
+        let nonce = Nonce::from_slice(&digest[..NONCE_LEN]);
+        let ciphertext = self
+            .cipher()
+            .encrypt(nonce, input.as_bytes())
+            .expect("inputs are small enough to encrypt");
+
+        let mut data = MAGIC.to_vec();
+        data.extend_from_slice(nonce);
+        data.extend(ciphertext);
+        data
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> io::Result<String> {
+        let invalid =
+            |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+
+        let data = data
+            .strip_prefix(MAGIC)
+            .filter(|data| data.len() >= NONCE_LEN)
+            .ok_or_else(|| invalid("not an encrypted input"))?;
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let input = self
+            .cipher()
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| invalid("can't decrypt the input, it was encrypted with another key"))?;
+        String::from_utf8(input).map_err(|_| invalid("the decrypted input is not text"))
+    }
+}
+
+/// Reads a day's input from `input.txt`, or decrypts `input.txt.enc` with the local key when there
+/// is no plain input.
+pub fn read_input(dir: &Path) -> io::Result<String> {
+    match fs::read_to_string(dir.join(INPUT_FILE)) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound && dir.join(ENCRYPTED_FILE).exists() => {
+            let data = fs::read(dir.join(ENCRYPTED_FILE))?;
+            InputKey::load()?.decrypt(&data)
+        }
+        result => result,
+    }
+}
+
+/// Writes `input.txt.enc` beside every `input.txt` in `dirs` that has changed since it was last
+/// encrypted. Returns the files written.
+pub fn encrypt_all(key: &InputKey, dirs: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for dir in dirs {
+        let input = match fs::read_to_string(dir.join(INPUT_FILE)) {
+            Ok(input) => input,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        let path = dir.join(ENCRYPTED_FILE);
+        let data = key.encrypt(&input);
+        if fs::read(&path).ok().as_ref() != Some(&data) {
+            fs::write(&path, data)?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+/// Writes `input.txt` from every `input.txt.enc` in `dirs` where it is missing or different.
+/// Returns the files written.
+pub fn decrypt_all(key: &InputKey, dirs: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for dir in dirs {
+        let data = match fs::read(dir.join(ENCRYPTED_FILE)) {
+            Ok(data) => data,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        let path = dir.join(INPUT_FILE);
+        let input = key.decrypt(&data).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("{}: {e}", dir.join(ENCRYPTED_FILE).display()),
+            )
+        })?;
+        if fs::read_to_string(&path).ok().as_ref() != Some(&input) {
+            fs::write(&path, input)?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::store::{decrypt_all, encrypt_all, InputKey, ENCRYPTED_FILE, INPUT_FILE};
+    use std::fs;
+
+    fn key(digit: char) -> InputKey {
+        InputKey::from_hex(&digit.to_string().repeat(64)).unwrap()
+    }
+
+    #[test]
+    fn test_encrypt() {
+        let key = key('a');
+        let data = key.encrypt("1abc2\npqr3stu8vwx\n");
+
+        assert!(!data.windows(5).any(|window| window == b"1abc2"));
+        assert_eq!(key.decrypt(&data).unwrap(), "1abc2\npqr3stu8vwx\n");
+        // the same input gives the same file
+        assert_eq!(key.encrypt("1abc2\npqr3stu8vwx\n"), data);
+        assert!(self::key('b').decrypt(&data).is_err());
+
+        let mut tampered = data.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(key.decrypt(&tampered).is_err());
+        assert!(key.decrypt(b"1abc2").is_err());
+    }
+    #[test]
+    fn test_from_hex() {
+        assert_eq!(key('0').to_hex(), "0".repeat(64));
+        assert!(InputKey::from_hex("abc").is_err());
+        assert!(InputKey::from_hex(&"g".repeat(64)).is_err());
+    }
+    #[test]
+    fn test_encrypt_all() {
+        let dir = std::env::temp_dir().join(format!("aoc_store_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(INPUT_FILE), "1 2\n").unwrap();
+        let dirs = [dir.clone()];
+        let key = key('c');
+
+        let encrypted = encrypt_all(&key, &dirs).unwrap();
+        let unchanged = encrypt_all(&key, &dirs).unwrap();
+        fs::remove_file(dir.join(INPUT_FILE)).unwrap();
+        let decrypted = decrypt_all(&key, &dirs).unwrap();
+        let input = fs::read_to_string(dir.join(INPUT_FILE));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(encrypted, [dir.join(ENCRYPTED_FILE)]);
+        assert!(unchanged.is_empty());
+        assert_eq!(decrypted, [dir.join(INPUT_FILE)]);
+        assert_eq!(input.unwrap(), "1 2\n");
+    }
+}
@@ -0,0 +1,21 @@
+//! The answer written in `answers.toml` and `examples.toml`. The macros crate includes this file
+//! too, a proc-macro crate can't share types with the library it is a dependency of.
+
+use serde::Deserialize;
+
+// answers can be written as toml integers or as strings for anything else
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Answer {
+    Number(i64),
+    Text(String),
+}
+impl Answer {
+    /// The answer as a part's `Display` prints it.
+    pub(crate) fn render(&self) -> String {
+        match self {
+            Answer::Number(n) => n.to_string(),
+            Answer::Text(s) => s.clone(),
+        }
+    }
+}